@@ -42,7 +42,7 @@ fn TimerControll(timer: Signal<DioxusTimer>) -> Element {
     };
 
     let controller = match timer.read().state() {
-        TimerState::Inactive => rsx! {
+        TimerState::Inactive | TimerState::Stopped => rsx! {
             button { onclick: start_handle, "Start" }
             button { onclick: reset_handle, "Reset" }
         },
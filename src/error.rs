@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+/// Errors returned by fallible `DioxusTimer` operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerError {
+    /// The preset duration is too large to represent as a target instant.
+    PresetTooLarge,
+    /// The target instant has already passed.
+    TargetInPast,
+    /// The interval sequence is empty.
+    EmptyIntervals,
+    /// The countdown cannot start because its preset duration is zero.
+    ZeroPreset,
+    /// The query string does not describe a timer.
+    InvalidQuery,
+    /// The timer is finished or a stopwatch, so its preset cannot be changed.
+    PresetLocked,
+}
+
+impl Display for TimerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            TimerError::PresetTooLarge => "preset duration is too large",
+            TimerError::TargetInPast => "target instant has already passed",
+            TimerError::EmptyIntervals => "interval sequence is empty",
+            TimerError::ZeroPreset => "preset duration is zero",
+            TimerError::InvalidQuery => "query string does not describe a timer",
+            TimerError::PresetLocked => "preset cannot be changed in the current state",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl std::error::Error for TimerError {}
@@ -1,21 +1,46 @@
 use dioxus::prelude::*;
-use std::fmt::Display;
+use futures_util::Stream;
+use std::fmt::{Display, Write};
+use std::hash::{Hash, Hasher};
 
-#[cfg(target_arch = "wasm32")]
+mod error;
+mod map;
+pub use error::TimerError;
+pub use map::{use_timer_map, use_timer_map_provider, TimerMap};
+
+#[cfg(any(target_arch = "wasm32", feature = "runtime-async-std"))]
 use async_std::task::sleep;
 #[cfg(target_arch = "wasm32")]
 use instant::{Duration, Instant};
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{Duration, Instant};
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "runtime-async-std"),
+    feature = "runtime-tokio"
+))]
 use tokio::time::sleep;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "runtime-async-std"),
+    not(feature = "runtime-tokio")
+))]
+compile_error!(
+    "dioxus-timer requires either the `runtime-tokio` or the `runtime-async-std` feature"
+);
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimerState {
     Inactive,
     Working,
     Finished,
     Paused,
+    Stopped,
 }
 
 impl Display for TimerState {
@@ -25,176 +50,3202 @@ impl Display for TimerState {
             TimerState::Working => "Working",
             TimerState::Finished => "Finished",
             TimerState::Paused => "Paused",
+            TimerState::Stopped => "Stopped",
         };
         write!(f, "{text}")
     }
 }
 
-#[derive(Debug, Clone)]
+/// Selects whether a `DioxusTimer` counts down toward a preset or up from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TimerMode {
+    Countdown,
+    Stopwatch,
+}
+
+impl Display for TimerMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            TimerMode::Countdown => "Countdown",
+            TimerMode::Stopwatch => "Stopwatch",
+        };
+        write!(f, "{text}")
+    }
+}
+
+/// Selects how fractional seconds are rounded by `DioxusTimer::format_rounded`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds down, like the `Display` impl
+    Floor,
+    /// Rounds up, so a countdown only shows zero once it has truly run out
+    Ceil,
+    /// Rounds to the nearest second
+    Round,
+}
+
+/// Urgency of a countdown, returned by `DioxusTimer::zone` for styling the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerZone {
+    /// More time is left than the warn threshold
+    Normal,
+    /// The remaining time is at or below the warn threshold
+    Warn,
+    /// The remaining time is at or below the danger threshold
+    Danger,
+    /// The countdown has finished
+    Finished,
+}
+
+/// A lap recorded by `DioxusTimer::lap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lap {
+    /// Elapsed time when the lap was recorded
+    pub elapsed: Duration,
+    /// Time since the previous lap
+    pub split: Duration,
+}
+
+/// A plain copy of a timer's progress, returned by `DioxusTimer::snapshot`.
+///
+/// Durations are stored instead of instants, so the snapshot can be persisted in any format
+/// and restored later with `DioxusTimer::from_snapshot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimerSnapshot {
+    /// Preset duration of the countdown
+    pub preset: Duration,
+    /// Time left when the snapshot was taken
+    pub remaining: Duration,
+    /// State when the snapshot was taken
+    pub state: TimerState,
+    /// Whether the timer counts down or up
+    pub mode: TimerMode,
+    /// Time counted when the snapshot was taken, which restores a stopwatch
+    pub elapsed: Duration,
+}
+
+/// Values derived from a single sample of a timer, returned by `DioxusTimer::view`.
+///
+/// Reading the fields from one view, rather than calling several methods on a signal that the
+/// update loop writes in between, guarantees that they are mutually consistent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimerView {
+    /// Remaining time, see `DioxusTimer::remaining_time`
+    pub remaining: Duration,
+    /// State of the timer
+    pub state: TimerState,
+    /// Elapsed time, see `DioxusTimer::elapsed`
+    pub elapsed: Duration,
+    /// Progress between `0.0` and `1.0`, see `DioxusTimer::progress`
+    pub progress: f32,
+}
+
+/// The remaining time of a countdown, returned by `DioxusTimer::remaining`.
+///
+/// It displays in the same `HH:MM:SS` clock format as `DioxusTimer` and compares with plain
+/// `Duration` values.
+///
+/// # Examples
+///
+/// ```
+/// # use dioxus_timer::DioxusTimer;
+/// # use std::time::Duration;
+///let timer = DioxusTimer::from_secs(90);
+///assert_eq!(timer.remaining().to_string(), "00:01:30");
+///assert!(timer.remaining() < Duration::from_secs(120));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RemainingTime(pub Duration);
+
+impl Display for RemainingTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Clock(self.0).fmt(f)
+    }
+}
+
+impl From<RemainingTime> for Duration {
+    fn from(remaining: RemainingTime) -> Self {
+        remaining.0
+    }
+}
+
+impl PartialEq<Duration> for RemainingTime {
+    fn eq(&self, other: &Duration) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<Duration> for RemainingTime {
+    fn partial_cmp(&self, other: &Duration) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/// A countdown timer or stopwatch driven by repeated calls to `update`.
+///
+/// With the `serde` feature the timer can be serialized. Since `Instant` cannot be
+/// persisted, the remaining and elapsed times are stored instead and the timer is
+/// rebuilt relative to `Instant::now()` on deserialization.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// # use dioxus_timer::{DioxusTimer, TimerState};
+/// # use std::time::Duration;
+/// let mut timer = DioxusTimer::new();
+/// timer.set_preset_time(Duration::from_secs(60));
+/// timer.start();
+/// timer.update();
+///
+/// let json = serde_json::to_string(&timer).unwrap();
+/// let restored: DioxusTimer = serde_json::from_str(&json).unwrap();
+/// assert_eq!(restored.state(), TimerState::Working);
+/// let diff = timer.remaining_time().abs_diff(restored.remaining_time());
+/// assert!(diff < Duration::from_millis(100));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "SerializedTimer", into = "SerializedTimer")
+)]
 pub struct DioxusTimer {
+    mode: TimerMode,
     preset_duration: Duration,
+    /// Stores the deadline of a countdown, or the origin a stopwatch counts up from
     target_time: Instant,
     state: TimerState,
     /// Stores Instant::now()
     current_time: Instant,
     /// Stores paused time
     paused_time: Option<Instant>,
+    /// Stores the configured number of repeats, `None` means forever
+    repeat: Option<u32>,
+    /// Stores the repeats left in the current run
+    repeats_remaining: Option<u32>,
+    cycles_completed: u32,
+    laps: Vec<Lap>,
+    /// Stores the rate at which time passes while `Working`
+    speed: f64,
+    /// Stores the state at the end of the previous update
+    last_state: TimerState,
+    /// Stores the state change observed by the previous update
+    transition: Option<(TimerState, TimerState)>,
+    tick_count: u64,
+    /// Stores the total time spent paused since the last reset
+    paused_total: Duration,
+    /// Stores the sequence of presets the countdown cycles through
+    intervals: Vec<Duration>,
+    interval_index: usize,
+    /// Stores the wall-clock instant of the most recent start
+    started_at: Option<Instant>,
+    /// Stores the minimum time between updates by a driver
+    tick_interval: Duration,
+    /// Stores the maximum time a single update may advance the timer
+    max_tick: Option<Duration>,
+    /// Stores the warn and danger thresholds used by `zone`
+    thresholds: Option<(Duration, Duration)>,
+    /// Stores the display granularity below which driver updates are not written back
+    render_granularity: Duration,
+    /// Stores whether the timer was started since it was created or cleared
+    has_started: bool,
+    /// Stores the interval of repeat alarms while `Finished`
+    alarm_interval: Option<Duration>,
+    acknowledged: bool,
+    /// Stores whether the previous update emitted a repeat alarm
+    alarm_due: bool,
+    /// Stores the working time after which `update` pauses the timer
+    auto_pause: Option<Duration>,
+    /// Stores the time spent `Working` since the timer was last started or resumed
+    working_time: Duration,
+    auto_paused: bool,
+    /// Stores the time a countdown keeps `Working` after reaching its target time
+    grace: Duration,
+    /// Stores the instant the timer last entered the `Finished` state
+    finished_at: Option<Instant>,
+    /// Stores the cap applied to presets by `set_preset_time`
+    max_preset: Option<Duration>,
+    was_clamped: bool,
 }
 
+/// Longest preset accepted by `DioxusTimer::set_preset_secs`, 100 years of 365 days.
+const MAX_PRESET_SECS: u64 = 100 * 365 * 24 * 60 * 60;
+
 impl DioxusTimer {
     /// Creates a new `DioxusTimer` instance with default settings.
     pub fn new() -> Self {
         let current_time = Instant::now();
         let target_time = current_time;
         Self {
+            mode: TimerMode::Countdown,
             preset_duration: Duration::ZERO,
             target_time,
             state: TimerState::Inactive,
             current_time,
             paused_time: None,
+            repeat: Some(0),
+            repeats_remaining: Some(0),
+            cycles_completed: 0,
+            laps: Vec::new(),
+            speed: 1.0,
+            last_state: TimerState::Inactive,
+            transition: None,
+            tick_count: 0,
+            paused_total: Duration::ZERO,
+            intervals: Vec::new(),
+            interval_index: 0,
+            started_at: None,
+            tick_interval: Duration::ZERO,
+            max_tick: None,
+            thresholds: None,
+            render_granularity: Duration::ZERO,
+            has_started: false,
+            alarm_interval: None,
+            acknowledged: false,
+            alarm_due: false,
+            auto_pause: None,
+            working_time: Duration::ZERO,
+            auto_paused: false,
+            grace: Duration::ZERO,
+            finished_at: None,
+            max_preset: None,
+            was_clamped: false,
+        }
+    }
+
+    /// Creates a new `Inactive` countdown already set to `preset_duration`.
+    pub fn with_preset(preset_duration: Duration) -> Self {
+        let mut timer = Self::new();
+        timer.set_preset_time(preset_duration);
+        timer
+    }
+
+    /// Creates a new `Inactive` countdown set to `secs` seconds.
+    pub fn from_secs(secs: u64) -> Self {
+        Self::with_preset(Duration::from_secs(secs))
+    }
+
+    /// Creates a new `Inactive` countdown set to `minutes` minutes.
+    pub fn from_minutes(minutes: u64) -> Self {
+        Self::from_secs(minutes.saturating_mul(60))
+    }
+
+    /// Creates a new `Inactive` countdown set to the given hours, minutes and seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    ///let pomodoro = DioxusTimer::from_hms(0, 25, 0);
+    ///assert_eq!(pomodoro.preset_duration(), Duration::from_secs(25 * 60));
+    /// ```
+    pub fn from_hms(hours: u64, minutes: u64, secs: u64) -> Self {
+        let total = hours
+            .saturating_mul(3600)
+            .saturating_add(minutes.saturating_mul(60))
+            .saturating_add(secs);
+        Self::from_secs(total)
+    }
+
+    /// Creates a countdown that is already `Working` and finishes at the local date and time `datetime`.
+    ///
+    /// The time left until `datetime` becomes the preset duration, see `set_target_instant`.
+    /// If `datetime` has already passed, the timer is `Finished` immediately.
+    #[cfg(feature = "chrono")]
+    pub fn until(datetime: chrono::DateTime<chrono::Local>) -> Self {
+        let delta = (datetime - chrono::Local::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO);
+        let now = Instant::now();
+        let mut timer = Self::new();
+        timer.set_target_instant(now.checked_add(delta).unwrap_or(now));
+        timer
+    }
+
+    /// Creates a new `DioxusTimer` that counts up from zero instead of down.
+    ///
+    /// A stopwatch has no preset and never enters the `Finished` state on its own.
+    pub fn new_stopwatch() -> Self {
+        Self {
+            mode: TimerMode::Stopwatch,
+            ..Self::new()
+        }
+    }
+
+    /// Restores a countdown or stopwatch from a `TimerSnapshot`.
+    ///
+    /// The target time is reconstructed from `Instant::now()` and the remaining time, so a
+    /// `Working` snapshot keeps counting down from where it was taken. A remaining time above the
+    /// preset raises the preset to match it. A stopwatch keeps counting up from its elapsed time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerState};
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(60);
+    ///timer.start_from(Duration::from_secs(20));
+    ///timer.pause();
+    ///let restored = DioxusTimer::from_snapshot(timer.snapshot());
+    ///assert_eq!(restored.state(), TimerState::Paused);
+    ///assert_eq!(restored.remaining_time(), Duration::from_secs(20));
+    /// ```
+    pub fn from_snapshot(snapshot: TimerSnapshot) -> Self {
+        let mut timer = Self::new();
+        match snapshot.mode {
+            TimerMode::Countdown => {
+                timer.preset_duration = snapshot.preset.max(snapshot.remaining);
+                let remaining = match snapshot.state {
+                    TimerState::Inactive | TimerState::Stopped => snapshot.preset,
+                    TimerState::Finished => Duration::ZERO,
+                    TimerState::Working | TimerState::Paused => snapshot.remaining,
+                };
+                timer.target_time = timer
+                    .current_time
+                    .checked_add(remaining)
+                    .unwrap_or(timer.current_time);
+            }
+            TimerMode::Stopwatch => {
+                timer.mode = TimerMode::Stopwatch;
+                let elapsed = match snapshot.state {
+                    TimerState::Inactive | TimerState::Stopped => Duration::ZERO,
+                    _ => snapshot.elapsed,
+                };
+                timer.target_time = timer
+                    .current_time
+                    .checked_sub(elapsed)
+                    .unwrap_or(timer.current_time);
+            }
+        }
+        if snapshot.state != TimerState::Inactive {
+            timer.started_at = Some(timer.current_time);
+            timer.has_started = true;
+        }
+        timer.paused_time = (snapshot.state == TimerState::Paused).then_some(timer.current_time);
+        timer.finished_at = (snapshot.state == TimerState::Finished).then_some(timer.current_time);
+        timer.state = snapshot.state;
+        timer.last_state = snapshot.state;
+        timer
+    }
+
+    /// Returns the preset, remaining time, state, mode and elapsed time of the timer as a
+    /// `TimerSnapshot`.
+    pub fn snapshot(&self) -> TimerSnapshot {
+        TimerSnapshot {
+            preset: self.preset_duration,
+            remaining: self.remaining_time(),
+            state: self.state,
+            mode: self.mode,
+            elapsed: self.elapsed(),
+        }
+    }
+
+    /// Encodes the preset, remaining time and state as a URL-safe query string.
+    ///
+    /// Durations are stored in milliseconds, like `preset=90000&remaining=30000&state=Working`.
+    /// A stopwatch adds its mode and elapsed time, like `&mode=Stopwatch&elapsed=30000`.
+    /// Use `from_query` to restore the timer, for example from a shared link.
+    pub fn to_query(&self) -> String {
+        let snapshot = self.snapshot();
+        let mut query = format!(
+            "preset={}&remaining={}&state={}",
+            snapshot.preset.as_millis(),
+            snapshot.remaining.as_millis(),
+            snapshot.state
+        );
+        if snapshot.mode == TimerMode::Stopwatch {
+            query += &format!(
+                "&mode={}&elapsed={}",
+                snapshot.mode,
+                snapshot.elapsed.as_millis()
+            );
+        }
+        query
+    }
+
+    /// Restores a timer from a query string produced by `to_query`, see `from_snapshot`.
+    ///
+    /// A leading `?` and unknown parameters are ignored, and a query without `mode` restores a
+    /// countdown. Returns `TimerError::InvalidQuery` if a parameter is missing or malformed, or if
+    /// `remaining` exceeds `preset`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerError};
+    /// # use std::time::Duration;
+    ///let timer = DioxusTimer::from_query("?preset=90000&remaining=30000&state=Paused").unwrap();
+    ///assert_eq!(timer.remaining_time(), Duration::from_secs(30));
+    ///let query = "preset=0&remaining=0&state=Paused&mode=Stopwatch&elapsed=30000";
+    ///let stopwatch = DioxusTimer::from_query(query).unwrap();
+    ///assert_eq!(stopwatch.elapsed(), Duration::from_secs(30));
+    ///for query in [
+    ///    "",
+    ///    "preset=abc",
+    ///    "preset=90000&remaining=30000",
+    ///    "preset=90000&remaining=30000&state=Running",
+    ///    "preset&remaining=30000&state=Paused",
+    ///    "preset=99999999999999999999999&remaining=30000&state=Paused",
+    ///    "preset=30000&remaining=90000&state=Paused",
+    ///    "preset=0&remaining=0&state=Paused&mode=Alarm",
+    ///    "preset=0&remaining=0&state=Paused&mode=Stopwatch&elapsed=abc",
+    ///] {
+    ///    assert_eq!(DioxusTimer::from_query(query).unwrap_err(), TimerError::InvalidQuery);
+    ///}
+    /// ```
+    pub fn from_query(query: &str) -> Result<Self, TimerError> {
+        let mut preset = None;
+        let mut remaining = None;
+        let mut state = None;
+        let mut mode = TimerMode::Countdown;
+        let mut elapsed = Duration::ZERO;
+        let millis = |value: &str| value.parse().map(Duration::from_millis).ok();
+        for pair in query.trim_start_matches('?').split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match key {
+                "preset" => preset = Some(millis(value).ok_or(TimerError::InvalidQuery)?),
+                "remaining" => remaining = Some(millis(value).ok_or(TimerError::InvalidQuery)?),
+                "state" => {
+                    state = Some(match value {
+                        "Inactive" => TimerState::Inactive,
+                        "Working" => TimerState::Working,
+                        "Finished" => TimerState::Finished,
+                        "Paused" => TimerState::Paused,
+                        "Stopped" => TimerState::Stopped,
+                        _ => return Err(TimerError::InvalidQuery),
+                    })
+                }
+                "mode" => {
+                    mode = match value {
+                        "Countdown" => TimerMode::Countdown,
+                        "Stopwatch" => TimerMode::Stopwatch,
+                        _ => return Err(TimerError::InvalidQuery),
+                    }
+                }
+                "elapsed" => elapsed = millis(value).ok_or(TimerError::InvalidQuery)?,
+                _ => {}
+            }
+        }
+        match (preset, remaining, state) {
+            (Some(preset), Some(remaining), Some(state)) if remaining <= preset => {
+                Ok(Self::from_snapshot(TimerSnapshot {
+                    preset,
+                    remaining,
+                    state,
+                    mode,
+                    elapsed,
+                }))
+            }
+            _ => Err(TimerError::InvalidQuery),
+        }
+    }
+
+    /// Returns the remaining time, state, elapsed time and progress as one consistent `TimerView`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_timer::use_timer;
+    /// # use std::time::Duration;
+    /// # fn App() -> Element {
+    ///let timer = use_timer(Duration::from_millis(16));
+    ///let view = timer.read().view();
+    ///rsx!("{view.state}: {view.remaining:?} left")
+    /// # }
+    /// ```
+    pub fn view(&self) -> TimerView {
+        TimerView {
+            remaining: self.remaining_time(),
+            state: self.state,
+            elapsed: self.elapsed(),
+            progress: self.progress(),
         }
     }
 
+    /// Creates a `DioxusTimerBuilder` for configuring a countdown in one expression.
+    pub fn builder() -> DioxusTimerBuilder {
+        DioxusTimerBuilder::new()
+    }
+
+    /// Returns whether the timer counts down or up.
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
     /// Sets the preset duration for the timer.
+    ///
+    /// The preset replaces any sequence set by `set_intervals`.
+    /// Stopwatches have no preset, so the method does nothing for them.
+    /// A preset too large to represent results in a zero countdown, use `try_set_preset_time`
+    /// to detect this.
+    ///
+    /// A `Working` or `Paused` countdown restarts from the new preset rather than being extended,
+    /// see `add_time` to extend it instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(60);
+    ///timer.start();
+    ///timer.advance(Duration::from_secs(10));
+    ///timer.set_preset_time(Duration::from_secs(30));
+    ///assert_eq!(timer.remaining_time(), Duration::from_secs(30));
+    ///timer.advance(Duration::from_secs(5));
+    ///assert_eq!(timer.remaining_time(), Duration::from_secs(25));
+    /// ```
     pub fn set_preset_time(&mut self, preset_duration: Duration) {
-        if self.state == TimerState::Finished {
+        self.was_clamped = false;
+        if self.state == TimerState::Finished || self.mode == TimerMode::Stopwatch {
             return;
         }
+        let preset_duration = self.clamp_to_max_preset(preset_duration);
+        self.intervals.clear();
+        self.interval_index = 0;
         self.preset_duration = preset_duration;
+        let base_time = self.base_time();
+        self.target_time = base_time.checked_add(preset_duration).unwrap_or(base_time);
+    }
+
+    /// Caps the presets set by subsequent calls at `cap`, `None` meaning unlimited.
+    ///
+    /// Longer presets are clamped to the cap and reported by `was_clamped`. This covers
+    /// `set_preset_time`, each interval of `set_intervals` and the time left until
+    /// `set_target_instant`, as well as `add_time`, `set_remaining`, `start_from` and `snooze`,
+    /// which clamp the time they add or set so that the preset stays within the cap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::{Duration, Instant};
+    ///let mut timer = DioxusTimer::new();
+    ///timer.set_max_preset(Some(Duration::from_secs(3600)));
+    ///timer.set_preset_time(Duration::from_secs(7200));
+    ///assert_eq!(timer.preset_duration(), Duration::from_secs(3600));
+    ///assert!(timer.was_clamped());
+    ///timer.start();
+    ///timer.add_time(Duration::from_secs(60));
+    ///assert_eq!(timer.preset_duration(), Duration::from_secs(3600));
+    ///assert!(timer.was_clamped());
+    ///timer.set_target_instant(Instant::now());
+    ///timer.set_preset_time(Duration::from_secs(7200));
+    ///assert!(!timer.was_clamped());
+    ///timer.reset();
+    ///timer.set_intervals(vec![Duration::from_secs(60), Duration::from_secs(7200)]);
+    ///assert!(timer.was_clamped());
+    ///timer.set_target_instant(Instant::now() + Duration::from_secs(7200));
+    ///assert_eq!(timer.preset_duration(), Duration::from_secs(3600));
+    ///assert!(timer.was_clamped());
+    /// ```
+    pub fn set_max_preset(&mut self, cap: Option<Duration>) {
+        self.max_preset = cap;
+    }
+
+    /// Returns `true` if the most recent call that sets the preset was clamped by `set_max_preset`.
+    pub fn was_clamped(&self) -> bool {
+        self.was_clamped
+    }
+
+    /// Clamps `duration` to the cap set by `set_max_preset`, recording whether it was clamped.
+    fn clamp_to_max_preset(&mut self, duration: Duration) -> Duration {
+        let cap = self.max_preset.unwrap_or(Duration::MAX);
+        self.was_clamped = duration > cap;
+        duration.min(cap)
+    }
+
+    /// Sets the preset duration for the timer like `set_preset_time`.
+    ///
+    /// Returns `TimerError::PresetTooLarge` and leaves the timer untouched if the preset is
+    /// too large to represent as a target instant, and `TimerError::PresetLocked` if the timer
+    /// is `Finished` or a stopwatch, where `set_preset_time` does nothing.
+    pub fn try_set_preset_time(&mut self, preset_duration: Duration) -> Result<(), TimerError> {
+        if self.state == TimerState::Finished || self.mode == TimerMode::Stopwatch {
+            return Err(TimerError::PresetLocked);
+        }
+        let cap = self.max_preset.unwrap_or(Duration::MAX);
+        if self
+            .current_time
+            .checked_add(preset_duration.min(cap))
+            .is_none()
+        {
+            return Err(TimerError::PresetTooLarge);
+        }
+        self.set_preset_time(preset_duration);
+        Ok(())
+    }
+
+    /// Sets the preset duration to `secs` seconds like `set_preset_time`, for example from a form.
+    ///
+    /// Returns `TimerError::PresetTooLarge` and leaves the timer untouched for presets over
+    /// 100 years or too large to represent as a target instant, and `TimerError::PresetLocked`
+    /// if the timer is `Finished` or a stopwatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerError};
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::new();
+    ///assert_eq!(timer.set_preset_secs(90), Ok(()));
+    ///assert_eq!(timer.preset_duration(), Duration::from_secs(90));
+    ///assert_eq!(timer.set_preset_secs(u64::MAX), Err(TimerError::PresetTooLarge));
+    ///let mut stopwatch = DioxusTimer::new_stopwatch();
+    ///assert_eq!(stopwatch.set_preset_secs(30), Err(TimerError::PresetLocked));
+    /// ```
+    pub fn set_preset_secs(&mut self, secs: u64) -> Result<(), TimerError> {
+        if secs > MAX_PRESET_SECS {
+            return Err(TimerError::PresetTooLarge);
+        }
+        self.try_set_preset_time(Duration::from_secs(secs))
+    }
+
+    /// Starts a countdown that finishes at `when` instead of after a preset duration.
+    ///
+    /// The preset duration becomes the time left until `when`, clamped to the cap set by
+    /// `set_max_preset`, which then moves the target time earlier.
+    /// If `when` has already passed, the timer is `Finished` immediately.
+    /// Stopwatches have no target, so the method does nothing for them.
+    pub fn set_target_instant(&mut self, when: Instant) {
+        if self.mode == TimerMode::Stopwatch {
+            return;
+        }
+        self.current_time = Instant::now();
+        self.intervals.clear();
+        self.interval_index = 0;
+        self.preset_duration =
+            self.clamp_to_max_preset(when.saturating_duration_since(self.current_time));
         self.target_time = self
             .current_time
-            .checked_add(preset_duration)
-            .unwrap_or(self.current_time);
+            .checked_add(self.preset_duration)
+            .unwrap_or(when);
+        self.paused_time = None;
+        self.working_time = Duration::ZERO;
+        self.auto_paused = false;
+        self.started_at = Some(self.current_time);
+        self.has_started = true;
+        self.state = if self.preset_duration.is_zero() {
+            self.finished_at = Some(self.current_time);
+            TimerState::Finished
+        } else {
+            TimerState::Working
+        };
     }
 
-    /// Returns the remaining time on the timer.
-    pub fn remaining_time(&self) -> Duration {
+    /// Starts a countdown that finishes at `when` like `set_target_instant`.
+    ///
+    /// Returns `TimerError::TargetInPast` and leaves the timer untouched if `when` has already
+    /// passed, and `TimerError::PresetLocked` for a stopwatch, where `set_target_instant` does
+    /// nothing.
+    pub fn try_set_target_instant(&mut self, when: Instant) -> Result<(), TimerError> {
+        if self.mode == TimerMode::Stopwatch {
+            return Err(TimerError::PresetLocked);
+        }
+        if when <= Instant::now() {
+            return Err(TimerError::TargetInPast);
+        }
+        self.set_target_instant(when);
+        Ok(())
+    }
+
+    /// Returns the deadline of a countdown, or the instant a stopwatch counts up from.
+    ///
+    /// While `Paused` the target time keeps moving forward with each update.
+    pub fn target_time(&self) -> Instant {
         self.target_time
-            .checked_duration_since(self.current_time)
-            .unwrap_or(Duration::ZERO)
     }
 
-    /// Returns the current state of the timer.
-    pub fn state(&self) -> TimerState {
-        self.state
+    /// Sets a sequence of presets the countdown runs through one after another, like 30s work and 10s rest.
+    ///
+    /// The timer finishes after the last interval, or starts the sequence over if repeats remain.
+    /// An empty sequence removes the intervals and keeps the current preset.
+    /// Stopwatches have no preset, so the method does nothing for them.
+    pub fn set_intervals(&mut self, intervals: Vec<Duration>) {
+        self.was_clamped = false;
+        if self.state == TimerState::Finished || self.mode == TimerMode::Stopwatch {
+            return;
+        }
+        let cap = self.max_preset.unwrap_or(Duration::MAX);
+        self.was_clamped = intervals.iter().any(|&interval| interval > cap);
+        self.intervals = intervals
+            .into_iter()
+            .map(|interval| interval.min(cap))
+            .collect();
+        self.interval_index = 0;
+        if let Some(&first) = self.intervals.first() {
+            self.preset_duration = first;
+            let base_time = self.base_time();
+            self.target_time = base_time.checked_add(first).unwrap_or(base_time);
+        }
     }
 
-    /// Starts the timer if it is in the `Inactive` state.
+    /// Sets a sequence of presets like `set_intervals`.
     ///
-    /// If the preset duration is zero, the method does nothing.
-    pub fn start(&mut self) {
-        match self.state {
-            TimerState::Inactive => {
-                if self.preset_duration.is_zero() {
-                    return;
-                }
-                self.target_time = self
-                    .current_time
-                    .checked_add(self.preset_duration)
-                    .unwrap_or(self.current_time);
-                self.state = TimerState::Working;
-            }
-            TimerState::Paused => {
-                self.state = TimerState::Working;
-            }
-            _ => {}
+    /// Returns `TimerError::EmptyIntervals` and leaves the timer untouched if the sequence is
+    /// empty, and `TimerError::PresetLocked` if the timer is `Finished` or a stopwatch, where
+    /// `set_intervals` does nothing.
+    pub fn try_set_intervals(&mut self, intervals: Vec<Duration>) -> Result<(), TimerError> {
+        if self.state == TimerState::Finished || self.mode == TimerMode::Stopwatch {
+            return Err(TimerError::PresetLocked);
+        }
+        if intervals.is_empty() {
+            return Err(TimerError::EmptyIntervals);
         }
+        self.set_intervals(intervals);
+        Ok(())
     }
 
-    /// Pauses the timer if it is in the `Working` state.
-    pub fn pause(&mut self) {
-        if let TimerState::Working = self.state {
-            self.state = TimerState::Paused;
-            self.paused_time = Some(Instant::now());
+    /// Returns the index of the running interval set by `set_intervals`.
+    pub fn current_interval_index(&self) -> usize {
+        self.interval_index
+    }
+
+    /// Returns the instant a countdown will finish at if it keeps running, or `None` while
+    /// `Inactive` or `Stopped`.
+    ///
+    /// While `Paused` the instant keeps moving forward, since it assumes the timer resumes right away.
+    /// For a `Finished` timer it is the instant it finished at. Stopwatches never finish and return `None`.
+    pub fn finish_at(&self) -> Option<Instant> {
+        match (self.mode, self.state) {
+            (TimerMode::Stopwatch, _) | (_, TimerState::Inactive | TimerState::Stopped) => None,
+            _ => Some(self.target_time),
+        }
+    }
+
+    /// Returns the wall-clock instant a `Working` countdown reaches its target time, taking the
+    /// speed into account, so the update loop can wake up right then.
+    fn wake_at(&self) -> Option<Instant> {
+        if self.mode != TimerMode::Countdown || self.state != TimerState::Working {
+            return None;
+        }
+        let remaining = self
+            .target_time
+            .checked_add(self.grace)?
+            .saturating_duration_since(self.current_time);
+        let remaining = Duration::try_from_secs_f64(remaining.as_secs_f64() / self.speed).ok()?;
+        self.current_time.checked_add(remaining)
+    }
+
+    /// Returns the local date and time a countdown will finish at, see `finish_at`.
+    #[cfg(feature = "chrono")]
+    pub fn finish_at_local(&self) -> Option<chrono::DateTime<chrono::Local>> {
+        let finish_at = self.finish_at()?;
+        let now = Instant::now();
+        let local_now = chrono::Local::now();
+        if finish_at >= now {
+            local_now.checked_add_signed(chrono::TimeDelta::from_std(finish_at - now).ok()?)
+        } else {
+            local_now.checked_sub_signed(chrono::TimeDelta::from_std(now - finish_at).ok()?)
         }
     }
 
-    /// Resets the timer to its initial state or sets the target time for a new cycle.
+    /// Returns the preset duration of the timer.
+    pub fn preset_duration(&self) -> Duration {
+        self.preset_duration
+    }
+
+    /// Adds time to a countdown that is `Working` or `Paused`, extending its preset duration as well.
     ///
-    /// If the timer is in the `Finished` state, it transitions to the `Inactive` state.
-    pub fn reset(&mut self) {
-        if self.state == TimerState::Finished {
-            self.state = TimerState::Inactive;
+    /// The added time is clamped so that the preset stays within the cap set by `set_max_preset`.
+    pub fn add_time(&mut self, duration: Duration) {
+        if !self.is_adjustable() {
             return;
         }
+        let preset_duration = self
+            .clamp_to_max_preset(self.preset_duration.saturating_add(duration))
+            .max(self.preset_duration);
+        let duration = preset_duration - self.preset_duration;
+        self.preset_duration = preset_duration;
         self.target_time = self
-            .current_time
-            .checked_add(self.preset_duration)
-            .unwrap_or(self.current_time);
+            .target_time
+            .checked_add(duration)
+            .unwrap_or(self.target_time);
     }
 
-    /// Updates the timer's current time and checks for state transitions.
+    /// Subtracts time from a countdown that is `Working` or `Paused`, shortening its preset duration as well.
     ///
-    /// The `Working` state transitions to `Finished` when the target time is reached.
-    /// The `Paused` state adjusts the target time based on the time paused.
-    /// The `Inactive` state resets the timer.
-    pub fn update(&mut self) {
-        self.current_time = Instant::now();
+    /// Subtracting more than the remaining time makes the timer finish on the next update.
+    pub fn subtract_time(&mut self, duration: Duration) {
+        if !self.is_adjustable() {
+            return;
+        }
+        self.preset_duration = self.preset_duration.saturating_sub(duration);
+        self.target_time = self
+            .target_time
+            .checked_sub(duration)
+            .unwrap_or(self.current_time)
+            .max(self.current_time);
+    }
+
+    /// Sets the remaining time of a countdown that is `Working` or `Paused`, keeping the preset duration.
+    ///
+    /// A `Paused` timer is measured from the moment it was paused, so it resumes from exactly `remaining`.
+    /// If `remaining` exceeds the preset duration, the preset is raised to match it.
+    pub fn set_remaining(&mut self, remaining: Duration) {
+        if !self.is_adjustable() {
+            return;
+        }
+        let remaining = self.clamp_to_max_preset(remaining);
+        self.preset_duration = self.preset_duration.max(remaining);
+        let base_time = self.base_time();
+        self.target_time = base_time.checked_add(remaining).unwrap_or(base_time);
+    }
+
+    /// Returns the instant a new target time is measured from.
+    ///
+    /// A `Paused` timer is measured from the moment it was paused, since the next update moves
+    /// the target time forward by the time paused since then.
+    fn base_time(&self) -> Instant {
         match self.state {
-            TimerState::Working => {
-                if self
-                    .target_time
-                    .checked_duration_since(self.current_time)
-                    .is_none()
-                {
-                    self.state = TimerState::Finished;
-                }
-            }
-            TimerState::Paused => {
-                self.target_time = self
-                    .target_time
-                    .checked_add(self.current_time - self.paused_time.unwrap())
-                    .unwrap_or(self.current_time);
-                self.paused_time = Some(self.current_time);
-            }
-            TimerState::Inactive => {
-                self.reset();
-            }
-            _ => {}
+            TimerState::Paused => self.paused_time.unwrap_or(self.current_time),
+            _ => self.current_time,
         }
     }
-}
 
-impl Default for DioxusTimer {
-    fn default() -> Self {
-        Self::new()
+    /// Returns whether the target time of the countdown may be adjusted.
+    fn is_adjustable(&self) -> bool {
+        self.mode == TimerMode::Countdown
+            && matches!(self.state, TimerState::Working | TimerState::Paused)
     }
-}
 
-impl Display for DioxusTimer {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let rem_time = self.remaining_time().as_secs();
-        write!(
-            f,
-            "{:0>2}:{:0>2}:{:0>2}",
-            rem_time / 3600,
-            rem_time % 3600 / 60,
-            rem_time % 60,
-        )
+    /// Sets how many times a countdown restarts after reaching its target time.
+    ///
+    /// `None` repeats forever, `Some(n)` repeats `n` times before the timer stays `Finished`.
+    pub fn set_repeat(&mut self, count: Option<u32>) {
+        self.repeat = count;
+        self.repeats_remaining = count;
     }
-}
 
-/// Manages a DioxusTimer instance within the Dioxus GUI framework.
-///
-/// # Examples
-///
-/// ```
-///let mut timer = use_timer(Duration::from_millis(16));
-///use_effect(move || {
-///    spawn(async move {
-///        timer.write().set_preset_time(Duration::from_secs(10));
-///        timer.write().start();
-///    });
-///});
-///rsx!("{timer}"
-/// ```
-pub fn use_timer(tick: Duration) -> Signal<DioxusTimer> {
-    let mut timer = use_signal(DioxusTimer::new);
-    use_future(move || async move {
-        loop {
-            timer.write().update();
-            sleep(tick).await;
+    /// Returns the repeat count set by `set_repeat`, `None` meaning forever.
+    pub fn repeat_total(&self) -> Option<u32> {
+        self.repeat
+    }
+
+    /// Returns how many repeats are left in the current run, `None` meaning forever.
+    ///
+    /// Together with `repeat_total` this can render progress like "round 2 of 4".
+    pub fn repeats_remaining(&self) -> Option<u32> {
+        self.repeats_remaining
+    }
+
+    /// Returns how many times the countdown has reached its target time since the last reset.
+    pub fn cycles_completed(&self) -> u32 {
+        self.cycles_completed
+    }
+
+    /// Sets the rate at which time passes while the timer is `Working`.
+    ///
+    /// `2.0` runs the timer twice as fast and `0.5` at half speed.
+    /// Negative or non-finite multipliers are ignored.
+    pub fn set_speed(&mut self, multiplier: f64) {
+        if multiplier.is_finite() && multiplier >= 0.0 {
+            // `-0.0` is stored as `0.0`, so that equal speeds also hash equally.
+            self.speed = if multiplier == 0.0 { 0.0 } else { multiplier };
+        }
+    }
+
+    /// Returns the rate at which time passes while the timer is `Working`.
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Returns the remaining time on the timer.
+    ///
+    /// While `Working` the remaining time of a countdown never exceeds its preset duration.
+    pub fn remaining_time(&self) -> Duration {
+        self.target_time
+            .checked_duration_since(self.current_time)
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns the time counted so far.
+    ///
+    /// For a stopwatch this is the time spent `Working`, for a countdown it is the
+    /// part of the preset duration that has already run out.
+    /// Time spent `Paused` is never counted.
+    /// An `Inactive` or `Stopped` countdown returns zero and a `Finished` one the full preset duration.
+    pub fn elapsed(&self) -> Duration {
+        match (self.mode, self.state) {
+            (TimerMode::Countdown, TimerState::Inactive | TimerState::Stopped) => Duration::ZERO,
+            (TimerMode::Countdown, TimerState::Finished) => self.preset_duration,
+            (TimerMode::Countdown, _) => self.preset_duration.saturating_sub(self.remaining_time()),
+            (TimerMode::Stopwatch, _) => self
+                .current_time
+                .checked_duration_since(self.target_time)
+                .unwrap_or(Duration::ZERO),
+        }
+    }
+
+    /// Returns how long ago a `Finished` countdown reached its target time.
+    ///
+    /// The current time keeps being updated after the timer finishes, so the overtime grows
+    /// while `remaining_time` stays at zero. Returns zero in any other state.
+    pub fn overtime(&self) -> Duration {
+        match self.state {
+            TimerState::Finished => self
+                .current_time
+                .checked_duration_since(self.target_time)
+                .unwrap_or(Duration::ZERO),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Returns the wall-clock time since the timer was last started, or `None` while `Inactive`
+    /// or `Stopped`.
+    ///
+    /// Unlike `elapsed`, this includes time spent `Paused` and keeps growing after the timer finishes.
+    pub fn running_since(&self) -> Option<Duration> {
+        match self.state {
+            TimerState::Inactive | TimerState::Stopped => None,
+            _ => self
+                .started_at
+                .map(|started_at| self.current_time.saturating_duration_since(started_at)),
+        }
+    }
+
+    /// Returns how long ago the timer entered the `Finished` state, or `None` if it is not `Finished`.
+    ///
+    /// The time keeps growing with each update while `Finished`, so it suits dismissing a
+    /// "done" message a few seconds later. With a render granularity set, a driver loop writes
+    /// it back each time it crosses a multiple of the granularity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(5);
+    ///timer.start();
+    ///assert_eq!(timer.since_finished(), None);
+    ///timer.advance(Duration::from_secs(5));
+    ///timer.advance(Duration::from_secs(3));
+    ///assert_eq!(timer.since_finished(), Some(Duration::from_secs(3)));
+    /// ```
+    pub fn since_finished(&self) -> Option<Duration> {
+        match self.state {
+            TimerState::Finished => self
+                .finished_at
+                .map(|finished_at| self.current_time.saturating_duration_since(finished_at)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a `Working` countdown has at most `threshold` left, or if it is `Finished`.
+    ///
+    /// Returns `false` in any other state and for stopwatches.
+    pub fn is_within(&self, threshold: Duration) -> bool {
+        match (self.mode, self.state) {
+            (TimerMode::Countdown, TimerState::Working) => self.remaining_time() <= threshold,
+            (TimerMode::Countdown, TimerState::Finished) => true,
+            _ => false,
+        }
+    }
+
+    /// Sets the remaining times at which `zone` reports `TimerZone::Warn` and `TimerZone::Danger`.
+    ///
+    /// Without thresholds, `zone` reports `TimerZone::Normal` until the countdown finishes.
+    pub fn set_thresholds(&mut self, warn: Duration, danger: Duration) {
+        self.thresholds = Some((warn, danger));
+    }
+
+    /// Returns the urgency of the countdown based on the thresholds set by `set_thresholds`.
+    ///
+    /// `Inactive` and `Stopped` timers and stopwatches are always `TimerZone::Normal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerZone};
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(90);
+    ///timer.set_thresholds(Duration::from_secs(60), Duration::from_secs(10));
+    ///timer.start();
+    ///assert_eq!(timer.zone(), TimerZone::Normal);
+    ///timer.advance(Duration::from_secs(30));
+    ///assert_eq!(timer.zone(), TimerZone::Warn);
+    ///timer.advance(Duration::from_secs(50));
+    ///assert_eq!(timer.zone(), TimerZone::Danger);
+    /// ```
+    pub fn zone(&self) -> TimerZone {
+        match self.state {
+            TimerState::Finished => return TimerZone::Finished,
+            TimerState::Inactive | TimerState::Stopped => return TimerZone::Normal,
+            TimerState::Working | TimerState::Paused => {}
+        }
+        let Some((warn, danger)) = self.thresholds else {
+            return TimerZone::Normal;
+        };
+        if self.mode == TimerMode::Stopwatch {
+            return TimerZone::Normal;
+        }
+        let remaining = self.remaining_time();
+        if remaining <= danger {
+            TimerZone::Danger
+        } else if remaining <= warn {
+            TimerZone::Warn
+        } else {
+            TimerZone::Normal
+        }
+    }
+
+    /// Returns the remaining time like `remaining_time`, wrapped for display and comparison.
+    pub fn remaining(&self) -> RemainingTime {
+        RemainingTime(self.remaining_time())
+    }
+
+    /// Makes a `Finished` countdown emit a repeat alarm every `interval` until acknowledged.
+    ///
+    /// The alarm is reported by `is_alarm_due` for a single update, so an app can beep each time
+    /// it is set. `None`, the default, disables repeat alarms.
+    pub fn set_alarm_interval(&mut self, interval: Option<Duration>) {
+        self.alarm_interval = interval;
+    }
+
+    /// Returns `true` if the previous update emitted a repeat alarm, see `set_alarm_interval`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(5);
+    ///timer.set_alarm_interval(Some(Duration::from_secs(2)));
+    ///timer.start();
+    ///timer.advance(Duration::from_secs(5));
+    ///timer.advance(Duration::from_secs(2));
+    ///assert!(timer.is_alarm_due());
+    ///timer.acknowledge();
+    ///timer.advance(Duration::from_secs(2));
+    ///assert!(!timer.is_alarm_due());
+    /// ```
+    pub fn is_alarm_due(&self) -> bool {
+        self.alarm_due
+    }
+
+    /// Marks a `Finished` countdown as handled, stopping its repeat alarms.
+    ///
+    /// The acknowledgement is cleared by `reset` and each time the countdown finishes again.
+    pub fn acknowledge(&mut self) {
+        if self.state == TimerState::Finished {
+            self.acknowledged = true;
+            self.alarm_due = false;
+        }
+    }
+
+    /// Returns `true` if the `Finished` countdown was acknowledged with `acknowledge`.
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /// Makes `update` pause the timer once it has been `Working` for `after` since it was last
+    /// started or resumed, for example to prompt a break. `None` disables the auto-pause.
+    ///
+    /// Resuming with `start` clears `auto_paused` and arms the auto-pause for the next interval.
+    /// Stopping, resetting or re-arming the timer clears it as well.
+    pub fn set_auto_pause(&mut self, after: Option<Duration>) {
+        self.auto_pause = after;
+    }
+
+    /// Returns `true` if the timer is `Paused` because of `set_auto_pause`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerState};
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(60);
+    ///timer.set_auto_pause(Some(Duration::from_secs(20)));
+    ///timer.start();
+    ///timer.advance(Duration::from_secs(20));
+    ///assert_eq!(timer.state(), TimerState::Paused);
+    ///assert!(timer.auto_paused());
+    ///timer.start();
+    ///assert!(!timer.auto_paused());
+    /// ```
+    pub fn auto_paused(&self) -> bool {
+        self.auto_paused
+    }
+
+    /// Keeps a countdown `Working` for `grace` after it reaches its target time before it becomes
+    /// `Finished`, to allow last-moment input.
+    ///
+    /// During the grace period `remaining_time` is zero while the state is still `Working`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerState};
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(5);
+    ///timer.set_grace(Duration::from_millis(500));
+    ///timer.start();
+    ///timer.advance(Duration::from_secs(5));
+    ///assert_eq!(timer.state(), TimerState::Working);
+    ///timer.advance(Duration::from_millis(500));
+    ///assert_eq!(timer.state(), TimerState::Finished);
+    /// ```
+    pub fn set_grace(&mut self, grace: Duration) {
+        self.grace = grace;
+    }
+
+    /// Returns the remaining time on the timer in milliseconds.
+    pub fn remaining_millis(&self) -> u128 {
+        self.remaining_time().as_millis()
+    }
+
+    /// Returns the remaining time on the timer in whole seconds, truncated like the `Display` output.
+    pub fn remaining_secs(&self) -> u64 {
+        self.remaining_time().as_secs()
+    }
+
+    /// Returns how long until the displayed whole-second value changes.
+    ///
+    /// For a countdown this is the sub-second part of the remaining time, for a stopwatch the
+    /// time until the elapsed time reaches the next whole second. A driver can sleep this long
+    /// to flip the display right on the second boundary.
+    pub fn time_to_next_second(&self) -> Duration {
+        match self.mode {
+            TimerMode::Countdown => {
+                Duration::from_nanos(self.remaining_time().subsec_nanos().into())
+            }
+            TimerMode::Stopwatch => {
+                Duration::from_secs(1) - Duration::from_nanos(self.elapsed().subsec_nanos().into())
+            }
+        }
+    }
+
+    /// Returns the remaining time on the timer in seconds, including the fractional part.
+    pub fn remaining_secs_f64(&self) -> f64 {
+        self.remaining_time().as_secs_f64()
+    }
+
+    /// Formats the displayed time as `MM:SS.mmm`.
+    ///
+    /// Minutes are not wrapped at 60, so longer times keep counting in minutes.
+    pub fn format_with_millis(&self) -> String {
+        let time = self.display_time();
+        let secs = time.as_secs();
+        format!(
+            "{:0>2}:{:0>2}.{:0>3}",
+            secs / 60,
+            secs % 60,
+            time.subsec_millis()
+        )
+    }
+
+    /// Formats the displayed time as `HH:MM:SS`, rounding fractional seconds with `mode`.
+    pub fn format_rounded(&self, mode: RoundingMode) -> String {
+        let time = self.display_time();
+        let secs = match mode {
+            RoundingMode::Floor => time.as_secs(),
+            RoundingMode::Ceil => time.as_secs() + u64::from(time.subsec_nanos() > 0),
+            RoundingMode::Round => time.as_secs() + u64::from(time.subsec_millis() >= 500),
+        };
+        format!(
+            "{:0>2}:{:0>2}:{:0>2}",
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60
+        )
+    }
+
+    /// Formats the displayed time as `Dd HH:MM:SS`, like `12d 05:03:09`.
+    ///
+    /// Hours wrap at 24 and the day prefix is omitted under a day.
+    pub fn format_with_days(&self) -> String {
+        let secs = self.display_time().as_secs();
+        let clock = format!(
+            "{:0>2}:{:0>2}:{:0>2}",
+            secs % 86400 / 3600,
+            secs % 3600 / 60,
+            secs % 60
+        );
+        match secs / 86400 {
+            0 => clock,
+            days => format!("{days}d {clock}"),
+        }
+    }
+
+    /// Formats the displayed time without leading zero units.
+    ///
+    /// Shows `SS` under a minute, `MM:SS` under an hour and `HH:MM:SS` otherwise, unlike the
+    /// fixed-width `Display` impl.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    ///assert_eq!(DioxusTimer::from_secs(5).format_auto(), "05");
+    ///assert_eq!(DioxusTimer::from_secs(90).format_auto(), "01:30");
+    ///assert_eq!(DioxusTimer::from_hms(1, 2, 3).format_auto(), "01:02:03");
+    /// ```
+    pub fn format_auto(&self) -> String {
+        match self.components() {
+            (0, 0, seconds, _) => format!("{seconds:0>2}"),
+            (0, minutes, seconds, _) => format!("{minutes:0>2}:{seconds:0>2}"),
+            (hours, minutes, seconds, _) => format!("{hours:0>2}:{minutes:0>2}:{seconds:0>2}"),
+        }
+    }
+
+    /// Returns the hours, minutes, seconds and milliseconds of the displayed time.
+    ///
+    /// The displayed time is the remaining time of a countdown or the elapsed time of a stopwatch.
+    /// Hours are not wrapped at 24.
+    pub fn components(&self) -> (u64, u64, u64, u32) {
+        let time = self.display_time();
+        let secs = time.as_secs();
+        (
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+            time.subsec_millis(),
+        )
+    }
+
+    /// Formats the displayed time using strftime-like tokens.
+    ///
+    /// The displayed time is the remaining time of a countdown or the elapsed time of a stopwatch.
+    /// Supported tokens are `%H` for hours, `%M` for minutes, `%S` for seconds,
+    /// `%1f` to `%9f` for fractions of a second and `%%` for a literal `%`.
+    /// Hours are not wrapped at 24.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    /// let mut timer = DioxusTimer::new();
+    /// timer.set_preset_time(Duration::from_millis(90_250));
+    /// assert_eq!(timer.format("%M:%S.%3f"), "01:30.250");
+    /// assert_eq!(timer.format("%H:%M:%S"), timer.to_string());
+    /// ```
+    pub fn format(&self, fmt: &str) -> String {
+        let time = self.display_time();
+        let (hours, minutes, seconds, _) = self.components();
+        let mut text = String::with_capacity(fmt.len());
+        let mut chars = fmt.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                text.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('H') => write!(text, "{hours:0>2}").unwrap(),
+                Some('M') => write!(text, "{minutes:0>2}").unwrap(),
+                Some('S') => write!(text, "{seconds:0>2}").unwrap(),
+                Some('%') => text.push('%'),
+                Some(digit @ '1'..='9') if chars.peek() == Some(&'f') => {
+                    chars.next();
+                    let width = digit.to_digit(10).unwrap();
+                    let fraction = time.subsec_nanos() / 10u32.pow(9 - width);
+                    write!(text, "{:0>width$}", fraction, width = width as usize).unwrap();
+                }
+                Some(other) => {
+                    text.push('%');
+                    text.push(other);
+                }
+                None => text.push('%'),
+            }
+        }
+        text
+    }
+
+    /// Formats the remaining time as an ISO 8601 duration, like `PT4M12S`.
+    ///
+    /// Zero components are skipped and seconds are truncated like in the `Display` output.
+    /// Hours are not converted to days, and a zero remaining time gives `PT0S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    ///assert_eq!(DioxusTimer::from_hms(1, 0, 12).remaining_iso8601(), "PT1H12S");
+    ///assert_eq!(DioxusTimer::new().remaining_iso8601(), "PT0S");
+    /// ```
+    pub fn remaining_iso8601(&self) -> String {
+        let secs = self.remaining_secs();
+        if secs == 0 {
+            return "PT0S".to_string();
+        }
+        let mut text = "PT".to_string();
+        for (value, unit) in [
+            (secs / 3600, 'H'),
+            (secs % 3600 / 60, 'M'),
+            (secs % 60, 'S'),
+        ] {
+            if value != 0 {
+                write!(text, "{value}{unit}").unwrap();
+            }
+        }
+        text
+    }
+
+    /// Formats the displayed time compactly, like `1h 5m` or `1m 30s`, skipping zero components.
+    ///
+    /// Times under a second are shown in milliseconds, or as `less than a second` below one millisecond.
+    pub fn humanize(&self) -> String {
+        let time = self.display_time();
+        let secs = time.as_secs();
+        if secs == 0 {
+            return match time.subsec_millis() {
+                _ if time.is_zero() => "0s".to_string(),
+                0 => "less than a second".to_string(),
+                millis => format!("{millis}ms"),
+            };
+        }
+        [
+            (secs / 3600, "h"),
+            (secs % 3600 / 60, "m"),
+            (secs % 60, "s"),
+        ]
+        .into_iter()
+        .filter(|(value, _)| *value != 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Describes the timer for screen readers, like `4 minutes remaining`.
+    ///
+    /// The description is coarse so that it changes rarely when placed in a polite live region.
+    /// Countdowns are rounded up to whole hours or minutes, to steps of ten seconds in the last
+    /// minute and to single seconds in the last ten seconds. Stopwatches read `3 minutes elapsed`,
+    /// rounded down. An `Inactive` timer reads `timer not started`, a `Stopped` one `timer stopped`
+    /// and a `Finished` one `timer finished`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::new();
+    ///assert_eq!(timer.aria_label(), "timer not started");
+    ///timer.start_from(Duration::from_secs(181));
+    ///assert_eq!(timer.aria_label(), "4 minutes remaining");
+    /// ```
+    pub fn aria_label(&self) -> String {
+        match self.state {
+            TimerState::Inactive => return "timer not started".to_string(),
+            TimerState::Stopped => return "timer stopped".to_string(),
+            TimerState::Finished => return "timer finished".to_string(),
+            TimerState::Working | TimerState::Paused => {}
+        }
+        let (amount, unit, suffix) = match self.mode {
+            TimerMode::Countdown => {
+                let remaining = self.remaining_time();
+                let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() != 0);
+                let (amount, unit) = match secs {
+                    3600.. => (secs.div_ceil(3600), "hour"),
+                    60.. => (secs.div_ceil(60), "minute"),
+                    10.. => (secs.div_ceil(10) * 10, "second"),
+                    _ => (secs, "second"),
+                };
+                (amount, unit, "remaining")
+            }
+            TimerMode::Stopwatch => {
+                let secs = self.elapsed().as_secs();
+                let (amount, unit) = match secs {
+                    3600.. => (secs / 3600, "hour"),
+                    60.. => (secs / 60, "minute"),
+                    _ => (secs, "second"),
+                };
+                (amount, unit, "elapsed")
+            }
+        };
+        let plural = if amount == 1 { "" } else { "s" };
+        format!("{amount} {unit}{plural} {suffix}")
+    }
+
+    /// Formats the elapsed time in the same `HH:MM:SS` clock format as the `Display` impl.
+    ///
+    /// This shows a countdown counting up, see `elapsed`.
+    pub fn elapsed_display(&self) -> String {
+        Clock(self.elapsed()).to_string()
+    }
+
+    /// Returns the time shown by the display, which depends on the timer mode.
+    fn display_time(&self) -> Duration {
+        match self.mode {
+            TimerMode::Countdown => self.remaining_time(),
+            TimerMode::Stopwatch => self.elapsed(),
+        }
+    }
+
+    /// Returns the fraction of the preset duration that has elapsed, between `0.0` and `1.0`.
+    ///
+    /// Returns `0.0` while `Inactive` or `Stopped` or if the preset duration is zero, and `1.0`
+    /// once `Finished`.
+    pub fn progress(&self) -> f32 {
+        match self.state {
+            TimerState::Inactive | TimerState::Stopped => 0.0,
+            TimerState::Finished => 1.0,
+            _ if self.preset_duration.is_zero() => 0.0,
+            _ => {
+                (self.elapsed().as_secs_f64() / self.preset_duration.as_secs_f64()).clamp(0.0, 1.0)
+                    as f32
+            }
+        }
+    }
+
+    /// Returns `progress` as a whole percentage between `0` and `100`, for example for `aria-valuenow`.
+    pub fn percent_complete(&self) -> u8 {
+        (self.progress() * 100.0).round().clamp(0.0, 100.0) as u8
+    }
+
+    /// Records the current elapsed time as a lap.
+    ///
+    /// Laps are only recorded while the timer is `Working` or `Paused`.
+    pub fn lap(&mut self) {
+        if !matches!(self.state, TimerState::Working | TimerState::Paused) {
+            return;
+        }
+        let elapsed = self.elapsed();
+        let prev_elapsed = self.laps.last().map_or(Duration::ZERO, |lap| lap.elapsed);
+        self.laps.push(Lap {
+            elapsed,
+            split: elapsed.saturating_sub(prev_elapsed),
+        });
+    }
+
+    /// Returns the recorded laps, oldest first.
+    ///
+    /// Laps are kept after `stop` and cleared by the next `start`, `reset` or `clear`.
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    /// Removes all recorded laps.
+    pub fn clear_laps(&mut self) {
+        self.laps.clear();
+    }
+
+    /// Returns the current state of the timer.
+    pub fn state(&self) -> TimerState {
+        self.state
+    }
+
+    /// Returns the state change observed by the last `update`, as `(old, new)`.
+    ///
+    /// Changes made by calling methods such as `start` or `pause` are observed by the next update.
+    /// Returns `None` if the state did not change since the previous update.
+    pub fn transition(&self) -> Option<(TimerState, TimerState)> {
+        self.transition
+    }
+
+    /// Returns the total time spent `Paused` since the timer was last started or reset.
+    ///
+    /// The total is kept after `stop`, so it can still be shown once the timer is `Stopped`.
+    pub fn total_paused_time(&self) -> Duration {
+        self.paused_total
+    }
+
+    /// Sets the minimum time between updates by a driver loop such as the one of `use_timer`.
+    ///
+    /// The loop keeps waking at its own tick but skips updates, and the re-renders they cause,
+    /// until this interval has passed. A countdown reaching its target time is always updated.
+    pub fn set_tick_interval(&mut self, interval: Duration) {
+        self.tick_interval = interval;
+    }
+
+    /// Returns the minimum time between updates by a driver loop.
+    pub fn tick_interval(&self) -> Duration {
+        self.tick_interval
+    }
+
+    /// Limits how far a single `update` may advance a `Working` timer.
+    ///
+    /// Any time beyond the limit is treated as paused, so a long gap between updates, for example
+    /// after the machine wakes from sleep, does not skip the whole countdown.
+    /// The limit should be well above the tick of the driver loop. `None` removes the limit.
+    pub fn set_clamp_max_tick(&mut self, max_tick: Option<Duration>) {
+        self.max_tick = max_tick;
+    }
+
+    /// Returns the limit on how far a single `update` may advance the timer.
+    pub fn clamp_max_tick(&self) -> Option<Duration> {
+        self.max_tick
+    }
+
+    /// Sets the granularity at which a driver loop writes updates back to its signal.
+    ///
+    /// An update is only written, and only causes a re-render, when the state changes or the
+    /// displayed time, or `since_finished` once finished, crosses a multiple of `granularity`.
+    /// `Duration::from_secs(1)` suits a display of whole seconds. Skipped updates are not counted
+    /// by `tick_count`, and the granularity should stay below the limit set by
+    /// `set_clamp_max_tick`. Zero, the default, writes every update.
+    pub fn set_render_granularity(&mut self, granularity: Duration) {
+        self.render_granularity = granularity;
+    }
+
+    /// Returns the granularity at which a driver loop writes updates back to its signal.
+    pub fn render_granularity(&self) -> Duration {
+        self.render_granularity
+    }
+
+    /// Returns an updated copy of the timer if the update is visible at the render granularity.
+    fn updated(&self) -> Option<Self> {
+        let mut timer = self.clone();
+        timer.update();
+        let granularity = self.render_granularity.as_nanos();
+        let step = |time: Duration| time.as_nanos() / granularity;
+        let is_changed = granularity == 0
+            || timer.state != self.state
+            || timer.alarm_due
+            || step(timer.display_time()) != step(self.display_time())
+            || timer.since_finished().map(step) != self.since_finished().map(step);
+        is_changed.then_some(timer)
+    }
+
+    /// Returns `Instant::now()`, or the current time if `advance` or `update_at` moved it ahead.
+    fn now(&self) -> Instant {
+        Instant::now().max(self.current_time)
+    }
+
+    /// Returns `true` if a driver loop should update the timer at `now`.
+    ///
+    /// That is the case once the tick interval has passed since the last update, or when a
+    /// `Working` countdown has reached its target time.
+    pub fn is_update_due(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.current_time) >= self.tick_interval
+            || (self.state == TimerState::Working
+                && self.mode == TimerMode::Countdown
+                && now >= self.target_time)
+    }
+
+    /// Returns the current time sampled by the most recent update, or by methods such as `start`.
+    ///
+    /// If `Instant::now()` drifts far ahead of it, the driver loop has stopped updating the timer.
+    /// That only holds without a render granularity, as a driver loop only writes back updates
+    /// that are visible at the granularity set by `set_render_granularity`: the value may then lag
+    /// by up to the granularity while `Working` or `Finished`, and stays put while `Paused`.
+    pub fn last_update(&self) -> Instant {
+        self.current_time
+    }
+
+    /// Returns how many times `update` has been called.
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Returns `true` if the timer was ever started since it was created or last cleared.
+    ///
+    /// Unlike `is_inactive`, this tells an untouched timer apart from one that was stopped or reset.
+    pub fn has_started(&self) -> bool {
+        self.has_started
+    }
+
+    /// Returns `true` if the timer is in the `Working` state.
+    pub fn is_running(&self) -> bool {
+        self.state == TimerState::Working
+    }
+
+    /// Returns `true` if the timer is in the `Paused` state.
+    pub fn is_paused(&self) -> bool {
+        self.state == TimerState::Paused
+    }
+
+    /// Returns `true` if the timer is in the `Finished` state.
+    pub fn is_finished(&self) -> bool {
+        self.state == TimerState::Finished
+    }
+
+    /// Returns `true` if the timer is in the `Inactive` state.
+    pub fn is_inactive(&self) -> bool {
+        self.state == TimerState::Inactive
+    }
+
+    /// Returns `true` if the timer is in the `Stopped` state.
+    pub fn is_stopped(&self) -> bool {
+        self.state == TimerState::Stopped
+    }
+
+    /// Returns `true` if the remaining time is zero, regardless of the state.
+    ///
+    /// A countdown can show zero, for example after `subtract_time`, before the next update makes
+    /// it `Finished`, so this matches what the display shows. Stopwatches have no remaining time
+    /// and always return `true`.
+    pub fn is_at_zero(&self) -> bool {
+        self.remaining_time().is_zero()
+    }
+
+    /// Starts the timer if it is in the `Inactive`, `Stopped` or `Paused` state.
+    ///
+    /// If the preset duration of a countdown is zero, the method does nothing.
+    /// A stopwatch starts counting up from zero.
+    /// The countdown is measured from `Instant::now()`, since an idle timer is updated less often.
+    pub fn start(&mut self) {
+        if !self.can_start() {
+            return;
+        }
+        self.working_time = Duration::ZERO;
+        self.auto_paused = false;
+        match self.state {
+            TimerState::Inactive | TimerState::Stopped => {
+                self.reset();
+                self.current_time = self.now();
+                self.started_at = Some(self.current_time);
+                self.target_time = self
+                    .current_time
+                    .checked_add(self.preset_duration)
+                    .unwrap_or(self.current_time);
+                self.state = TimerState::Working;
+                self.has_started = true;
+            }
+            TimerState::Paused => {
+                self.state = TimerState::Working;
+            }
+            _ => {}
+        }
+    }
+
+    /// Starts the timer like `start`.
+    ///
+    /// Returns `TimerError::ZeroPreset` and leaves the timer untouched if it is an `Inactive` or
+    /// `Stopped` countdown with a zero preset duration, which `start` silently ignores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerError};
+    ///let mut timer = DioxusTimer::new();
+    ///assert_eq!(timer.try_start(), Err(TimerError::ZeroPreset));
+    /// ```
+    pub fn try_start(&mut self) -> Result<(), TimerError> {
+        if matches!(self.state, TimerState::Inactive | TimerState::Stopped)
+            && self.mode == TimerMode::Countdown
+            && self.preset_duration.is_zero()
+        {
+            return Err(TimerError::ZeroPreset);
+        }
+        self.start();
+        Ok(())
+    }
+
+    /// Starts a countdown running with `remaining` time left, keeping the preset duration.
+    ///
+    /// This is useful to resume a saved session. Stopwatches have no target, so the method does
+    /// nothing for them. If `remaining` exceeds the preset duration, the preset is raised to match it.
+    pub fn start_from(&mut self, remaining: Duration) {
+        if self.mode == TimerMode::Stopwatch {
+            return;
+        }
+        let remaining = self.clamp_to_max_preset(remaining);
+        self.preset_duration = self.preset_duration.max(remaining);
+        self.current_time = self.now();
+        self.started_at = Some(self.current_time);
+        self.paused_time = None;
+        self.working_time = Duration::ZERO;
+        self.auto_paused = false;
+        self.target_time = self
+            .current_time
+            .checked_add(remaining)
+            .unwrap_or(self.current_time);
+        self.state = TimerState::Working;
+        self.has_started = true;
+    }
+
+    /// Sets the preset duration of a countdown and starts it from there in a single call.
+    ///
+    /// Unlike calling `set_preset_time` and `start` separately, a driver loop never observes the
+    /// timer in between, and this also works when the timer is `Working`, `Paused` or `Finished`.
+    /// Stopwatches have no preset, and a zero preset leaves the timer `Inactive`.
+    pub fn set_and_start(&mut self, preset_duration: Duration) {
+        if self.mode == TimerMode::Stopwatch {
+            return;
+        }
+        self.reset_with(preset_duration);
+        self.state = TimerState::Inactive;
+        self.paused_time = None;
+        self.start();
+    }
+
+    /// Returns `true` if `start` would change the state of the timer.
+    ///
+    /// That is the case for a `Paused` timer, and for an `Inactive` or `Stopped` one unless it
+    /// is a countdown with a zero preset duration.
+    pub fn can_start(&self) -> bool {
+        match self.state {
+            TimerState::Inactive | TimerState::Stopped => {
+                self.mode == TimerMode::Stopwatch || !self.preset_duration.is_zero()
+            }
+            TimerState::Paused => true,
+            TimerState::Working | TimerState::Finished => false,
+        }
+    }
+
+    /// Pauses the timer if it is in the `Working` state.
+    pub fn pause(&mut self) {
+        if let TimerState::Working = self.state {
+            self.state = TimerState::Paused;
+            self.paused_time = Some(self.now());
+        }
+    }
+
+    /// Pauses a `Working` timer, or starts it if it is `Inactive`, `Stopped` or `Paused`.
+    ///
+    /// A `Finished` timer is left untouched.
+    pub fn toggle(&mut self) {
+        match self.state {
+            TimerState::Working => self.pause(),
+            TimerState::Inactive | TimerState::Stopped | TimerState::Paused => self.start(),
+            TimerState::Finished => {}
+        }
+    }
+
+    /// Stops the timer if it is in the `Working` or `Paused` state.
+    ///
+    /// The timer enters the `Stopped` state, so a subsequent `start` begins a fresh cycle.
+    /// Unlike `Inactive`, `Stopped` tells that the timer ran before. Recorded laps and the total
+    /// paused time are kept until that `start`, so they can be shown after stopping.
+    pub fn stop(&mut self) {
+        if let TimerState::Working | TimerState::Paused = self.state {
+            self.state = TimerState::Stopped;
+            self.paused_time = None;
+            self.working_time = Duration::ZERO;
+            self.auto_paused = false;
+            self.rearm();
+        }
+    }
+
+    /// Starts the timer over from the full preset duration, regardless of its current state.
+    ///
+    /// If the preset duration of a countdown is zero, the method does nothing.
+    pub fn restart(&mut self) {
+        if self.mode == TimerMode::Countdown && self.preset_duration.is_zero() {
+            return;
+        }
+        self.state = TimerState::Inactive;
+        self.paused_time = None;
+        self.reset();
+        self.start();
+    }
+
+    /// Returns a `Finished` countdown to `Working` with `extra` time left, like a snooze button.
+    ///
+    /// The countdown is measured from `Instant::now()`. If `extra` exceeds the preset duration,
+    /// the preset is raised to match it. In any other state the method does nothing.
+    pub fn snooze(&mut self, extra: Duration) {
+        if self.mode != TimerMode::Countdown || self.state != TimerState::Finished {
+            return;
+        }
+        let extra = self.clamp_to_max_preset(extra);
+        self.acknowledged = false;
+        self.alarm_due = false;
+        self.finished_at = None;
+        self.current_time = self.now();
+        self.preset_duration = self.preset_duration.max(extra);
+        self.target_time = self
+            .current_time
+            .checked_add(extra)
+            .unwrap_or(self.current_time);
+        self.paused_time = None;
+        self.working_time = Duration::ZERO;
+        self.auto_paused = false;
+        self.state = TimerState::Working;
+        self.has_started = true;
+    }
+
+    /// Sets the target time back to the full preset duration without changing the state.
+    ///
+    /// A `Working` countdown keeps running from the full preset and a `Paused` one stays paused
+    /// with the full preset remaining. A stopwatch starts counting from zero again.
+    /// A `Finished` timer is left untouched, see `reset` and `restart`.
+    pub fn rewind(&mut self) {
+        if self.state == TimerState::Finished {
+            return;
+        }
+        let base_time = self.base_time();
+        self.target_time = base_time
+            .checked_add(self.preset_duration)
+            .unwrap_or(base_time);
+    }
+
+    /// Returns the timer to the `Inactive` state from any state, keeping the preset duration.
+    ///
+    /// The repeat count, completed cycles, laps, total paused time and interval sequence start
+    /// over as with `reset`, so the next `start` begins a fresh cycle.
+    pub fn clear(&mut self) {
+        self.state = TimerState::Inactive;
+        self.paused_time = None;
+        self.started_at = None;
+        self.has_started = false;
+        self.reset();
+    }
+
+    /// Rearms the timer for the next cycle.
+    ///
+    /// If the timer is in the `Finished` state, it transitions to the `Inactive` state.
+    /// Otherwise the target time is set to the full preset duration from the current time.
+    /// Use `rewind` to only restore the preset or `clear` to always return to `Inactive`.
+    /// The repeat count, completed cycles, total paused time and interval sequence start over
+    /// and recorded laps are cleared as well.
+    pub fn reset(&mut self) {
+        self.repeats_remaining = self.repeat;
+        self.acknowledged = false;
+        self.alarm_due = false;
+        self.cycles_completed = 0;
+        self.laps.clear();
+        self.paused_total = Duration::ZERO;
+        self.working_time = Duration::ZERO;
+        self.auto_paused = false;
+        if self.state == TimerState::Finished {
+            self.state = TimerState::Inactive;
+            self.restore_first_interval();
+            return;
+        }
+        self.rearm();
+    }
+
+    /// Sets the target time to the full preset duration from the current time, starting the
+    /// interval sequence over.
+    fn rearm(&mut self) {
+        self.restore_first_interval();
+        self.target_time = self
+            .current_time
+            .checked_add(self.preset_duration)
+            .unwrap_or(self.current_time);
+    }
+
+    /// Makes the first interval set by `set_intervals`, if any, the running one.
+    fn restore_first_interval(&mut self) {
+        if let Some(&first) = self.intervals.first() {
+            self.interval_index = 0;
+            self.preset_duration = first;
+        }
+    }
+
+    /// Resets the timer like `reset` with a new preset duration.
+    ///
+    /// Unlike calling `set_preset_time` and `reset` separately, this also works in the `Finished` state,
+    /// which transitions to `Inactive`.
+    pub fn reset_with(&mut self, preset_duration: Duration) {
+        if self.state == TimerState::Finished {
+            self.state = TimerState::Inactive;
+        }
+        self.set_preset_time(preset_duration);
+        self.reset();
+    }
+
+    /// Updates the timer's current time and checks for state transitions.
+    ///
+    /// The `Working` state of a countdown transitions to `Finished` when the target time and any
+    /// grace period set by `set_grace` have passed, unless repeats remain, in which case it starts
+    /// the next cycle.
+    /// A `Working` stopwatch keeps counting up.
+    /// The `Paused` state adjusts the target time based on the time paused.
+    /// The `Inactive` and `Stopped` states rearm the target time to the full preset duration.
+    /// The `Finished` state only refreshes the current time, see `overtime`.
+    /// Any state change since the previous update is recorded and returned by `transition`.
+    pub fn update(&mut self) {
+        self.update_at(Instant::now());
+    }
+
+    /// Updates every timer in `timers` against a single read of `Instant::now()`, see `update_at`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    ///let mut timer = DioxusTimer::from_secs(10);
+    ///timer.start();
+    ///let mut timers = vec![timer.clone(), timer];
+    ///DioxusTimer::update_all(&mut timers);
+    ///assert_eq!(timers[0], timers[1]);
+    /// ```
+    pub fn update_all(timers: &mut [DioxusTimer]) {
+        let now = Instant::now();
+        for timer in timers {
+            timer.update_at(now);
+        }
+    }
+
+    /// Moves the timer forward by `by` of virtual time without sleeping.
+    ///
+    /// The current time advances by `by` and the same state transitions as in `update` are
+    /// checked, independent of the wall clock and any async runtime. This makes timer
+    /// behavior deterministic to test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerState};
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(5);
+    ///timer.start();
+    ///timer.advance(Duration::from_secs(5));
+    ///assert_eq!(timer.state(), TimerState::Finished);
+    /// ```
+    pub fn advance(&mut self, by: Duration) {
+        let now = self
+            .current_time
+            .checked_add(by)
+            .unwrap_or(self.current_time);
+        self.update_at(now);
+    }
+
+    /// Updates the timer like `update`, using `now` in place of `Instant::now()`.
+    ///
+    /// Feeding controlled instants makes the time accounting of updates deterministic to test.
+    /// Methods such as `start` and `pause` read the real clock, but never go back behind the
+    /// current time, so they line up with instants fed here or by `advance`. An instant before the
+    /// current time is treated as the current time, so the clock never runs backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::DioxusTimer;
+    /// # use std::time::Duration;
+    ///let mut timer = DioxusTimer::from_secs(10);
+    ///timer.start();
+    ///let started = timer.finish_at().unwrap() - Duration::from_secs(10);
+    ///timer.update_at(started + Duration::from_secs(4));
+    ///assert_eq!(timer.remaining_time(), Duration::from_secs(6));
+    ///timer.pause();
+    ///timer.update_at(started + Duration::from_secs(6));
+    ///timer.start();
+    ///timer.update_at(started + Duration::from_secs(7));
+    ///assert_eq!(timer.remaining_time(), Duration::from_secs(5));
+    ///timer.update_at(started);
+    ///assert_eq!(timer.remaining_time(), Duration::from_secs(5));
+    /// ```
+    pub fn update_at(&mut self, now: Instant) {
+        let now = now.max(self.current_time);
+        self.tick_count = self.tick_count.wrapping_add(1);
+        let prev_state = self.state;
+        let prev_time = self.current_time;
+        self.current_time = now;
+        self.alarm_due = false;
+        match self.state {
+            TimerState::Working => {
+                let delta = self.current_time.saturating_duration_since(prev_time);
+                let advance = self.max_tick.map_or(delta, |max_tick| delta.min(max_tick));
+                if advance < delta {
+                    self.target_time = self
+                        .target_time
+                        .checked_add(delta - advance)
+                        .unwrap_or(self.target_time);
+                }
+                if self.speed != 1.0 {
+                    self.apply_speed(advance);
+                }
+                let is_past_grace = self
+                    .target_time
+                    .checked_add(self.grace)
+                    .is_some_and(|deadline| deadline <= self.current_time);
+                if self.mode == TimerMode::Countdown && is_past_grace {
+                    self.complete_cycle();
+                }
+                self.working_time = self.working_time.saturating_add(advance);
+                if self.state == TimerState::Working
+                    && self
+                        .auto_pause
+                        .is_some_and(|after| self.working_time >= after)
+                {
+                    self.state = TimerState::Paused;
+                    self.paused_time = Some(self.current_time);
+                    self.auto_paused = true;
+                }
+            }
+            TimerState::Paused => {
+                // Without a recorded pause there is nothing to adjust until the next update.
+                if let Some(paused_time) = self.paused_time {
+                    let paused = self.current_time.saturating_duration_since(paused_time);
+                    self.paused_total = self.paused_total.saturating_add(paused);
+                    self.target_time = self
+                        .target_time
+                        .checked_add(paused)
+                        .unwrap_or(self.current_time);
+                }
+                self.paused_time = Some(self.current_time);
+            }
+            TimerState::Inactive | TimerState::Stopped => {
+                self.rearm();
+            }
+            TimerState::Finished => {
+                if let Some(interval) = self.alarm_interval.filter(|interval| !interval.is_zero()) {
+                    let alarms = |time: Instant| {
+                        time.saturating_duration_since(self.target_time).as_nanos()
+                            / interval.as_nanos()
+                    };
+                    self.alarm_due =
+                        !self.acknowledged && alarms(self.current_time) > alarms(prev_time);
+                }
+            }
+        }
+        // A countdown snoozed since the previous update may finish again without `last_state`
+        // ever seeing it `Working`.
+        let old_state = if self.last_state == self.state {
+            prev_state
+        } else {
+            self.last_state
+        };
+        if self.state == TimerState::Finished && old_state != TimerState::Finished {
+            self.acknowledged = false;
+            self.finished_at = Some(self.current_time);
+        }
+        self.transition = (old_state != self.state).then_some((old_state, self.state));
+        self.last_state = self.state;
+    }
+
+    /// Shifts the target time so that `delta` of wall-clock time counts as `delta * speed`.
+    fn apply_speed(&mut self, delta: Duration) {
+        let scaled =
+            Duration::try_from_secs_f64(delta.as_secs_f64() * self.speed).unwrap_or(Duration::MAX);
+        self.target_time = if scaled > delta {
+            self.target_time.checked_sub(scaled - delta)
+        } else {
+            self.target_time.checked_add(delta - scaled)
+        }
+        .unwrap_or(self.target_time);
+    }
+
+    /// Handles a countdown reaching its target time, either advancing to the next interval,
+    /// finishing or starting the next cycle.
+    fn complete_cycle(&mut self) {
+        if let Some(&next) = self.intervals.get(self.interval_index + 1) {
+            self.interval_index += 1;
+            self.preset_duration = next;
+            self.target_time = self
+                .current_time
+                .checked_add(next)
+                .unwrap_or(self.current_time);
+            return;
+        }
+        self.cycles_completed += 1;
+        match self.repeats_remaining {
+            Some(0) => self.state = TimerState::Finished,
+            repeats_remaining => {
+                self.repeats_remaining = repeats_remaining.map(|n| n - 1);
+                if let Some(&first) = self.intervals.first() {
+                    self.interval_index = 0;
+                    self.preset_duration = first;
+                }
+                self.target_time = self
+                    .current_time
+                    .checked_add(self.preset_duration)
+                    .unwrap_or(self.current_time);
+            }
+        }
+    }
+}
+
+/// `speed` is always finite because `set_speed` rejects anything else, so equality is total.
+impl Eq for DioxusTimer {}
+
+/// Hashes every field compared by `PartialEq`, with `speed` hashed by its bits.
+impl Hash for DioxusTimer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            mode,
+            preset_duration,
+            target_time,
+            state: timer_state,
+            current_time,
+            paused_time,
+            repeat,
+            repeats_remaining,
+            cycles_completed,
+            laps,
+            speed,
+            last_state,
+            transition,
+            tick_count,
+            paused_total,
+            intervals,
+            interval_index,
+            started_at,
+            tick_interval,
+            max_tick,
+            thresholds,
+            render_granularity,
+            has_started,
+            alarm_interval,
+            acknowledged,
+            alarm_due,
+            auto_pause,
+            working_time,
+            auto_paused,
+            grace,
+            finished_at,
+            max_preset,
+            was_clamped,
+        } = self;
+        mode.hash(state);
+        preset_duration.hash(state);
+        target_time.hash(state);
+        timer_state.hash(state);
+        current_time.hash(state);
+        paused_time.hash(state);
+        repeat.hash(state);
+        repeats_remaining.hash(state);
+        cycles_completed.hash(state);
+        laps.hash(state);
+        speed.to_bits().hash(state);
+        last_state.hash(state);
+        transition.hash(state);
+        tick_count.hash(state);
+        paused_total.hash(state);
+        intervals.hash(state);
+        interval_index.hash(state);
+        started_at.hash(state);
+        tick_interval.hash(state);
+        max_tick.hash(state);
+        thresholds.hash(state);
+        render_granularity.hash(state);
+        has_started.hash(state);
+        alarm_interval.hash(state);
+        acknowledged.hash(state);
+        alarm_due.hash(state);
+        auto_pause.hash(state);
+        working_time.hash(state);
+        auto_paused.hash(state);
+        grace.hash(state);
+        finished_at.hash(state);
+        max_preset.hash(state);
+        was_clamped.hash(state);
+    }
+}
+
+impl Default for DioxusTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Configures a countdown `DioxusTimer` in one expression.
+///
+/// # Examples
+///
+/// ```
+/// # use dioxus_timer::{DioxusTimer, TimerState};
+/// # use std::time::Duration;
+/// let timer = DioxusTimer::builder()
+///     .preset(Duration::from_secs(25 * 60))
+///     .repeat(Some(3))
+///     .auto_start(true)
+///     .build();
+/// assert_eq!(timer.state(), TimerState::Working);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DioxusTimerBuilder {
+    preset: Duration,
+    repeat: Option<u32>,
+    speed: f64,
+    auto_start: bool,
+}
+
+impl DioxusTimerBuilder {
+    /// Creates a new `DioxusTimerBuilder` with the same settings as `DioxusTimer::new`.
+    pub fn new() -> Self {
+        Self {
+            preset: Duration::ZERO,
+            repeat: Some(0),
+            speed: 1.0,
+            auto_start: false,
+        }
+    }
+
+    /// Sets the preset duration, see `DioxusTimer::set_preset_time`.
+    pub fn preset(mut self, preset: Duration) -> Self {
+        self.preset = preset;
+        self
+    }
+
+    /// Sets the repeat count, see `DioxusTimer::set_repeat`.
+    pub fn repeat(mut self, count: Option<u32>) -> Self {
+        self.repeat = count;
+        self
+    }
+
+    /// Sets the speed multiplier, see `DioxusTimer::set_speed`.
+    pub fn speed(mut self, multiplier: f64) -> Self {
+        self.speed = multiplier;
+        self
+    }
+
+    /// Sets whether the timer is started as soon as it is built.
+    pub fn auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Builds the configured `DioxusTimer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `auto_start` is requested without a non-zero preset duration, use `try_build`
+    /// to handle this instead.
+    pub fn build(self) -> DioxusTimer {
+        assert!(
+            !self.auto_start || !self.preset.is_zero(),
+            "auto_start requires a non-zero preset duration"
+        );
+        self.build_unchecked()
+    }
+
+    /// Builds the configured `DioxusTimer` like `build`.
+    ///
+    /// Returns `TimerError::ZeroPreset` if `auto_start` is requested without a non-zero preset
+    /// duration, where `build` panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dioxus_timer::{DioxusTimer, TimerError};
+    ///let result = DioxusTimer::builder().auto_start(true).try_build();
+    ///assert_eq!(result.unwrap_err(), TimerError::ZeroPreset);
+    /// ```
+    pub fn try_build(self) -> Result<DioxusTimer, TimerError> {
+        if self.auto_start && self.preset.is_zero() {
+            return Err(TimerError::ZeroPreset);
+        }
+        Ok(self.build_unchecked())
+    }
+
+    /// Builds the configured `DioxusTimer` once the settings have been checked.
+    fn build_unchecked(self) -> DioxusTimer {
+        let mut timer = DioxusTimer::new();
+        timer.set_preset_time(self.preset);
+        timer.set_repeat(self.repeat);
+        timer.set_speed(self.speed);
+        if self.auto_start {
+            timer.start();
+        }
+        timer
+    }
+}
+
+impl Default for DioxusTimerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Displays the remaining time of a countdown, or the elapsed time of a stopwatch.
+impl Display for DioxusTimer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Clock(self.display_time()).fmt(f)
+    }
+}
+
+/// Displays a duration in the `HH:MM:SS` clock format, with hours not wrapped at 24.
+struct Clock(Duration);
+
+impl Display for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let secs = self.0.as_secs();
+        let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+        write!(f, "{hours:0>2}:{minutes:0>2}:{seconds:0>2}")
+    }
+}
+
+/// Serialized form of `DioxusTimer`, storing durations in place of instants.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SerializedTimer {
+    mode: TimerMode,
+    preset_duration: Duration,
+    remaining_time: Duration,
+    elapsed: Duration,
+    running_since: Option<Duration>,
+    state: TimerState,
+    repeat: Option<u32>,
+    repeats_remaining: Option<u32>,
+    cycles_completed: u32,
+    laps: Vec<Lap>,
+    speed: f64,
+    paused_total: Duration,
+    intervals: Vec<Duration>,
+    interval_index: usize,
+    tick_interval: Duration,
+    max_tick: Option<Duration>,
+    thresholds: Option<(Duration, Duration)>,
+    render_granularity: Duration,
+    has_started: bool,
+    alarm_interval: Option<Duration>,
+    acknowledged: bool,
+    auto_pause: Option<Duration>,
+    working_time: Duration,
+    auto_paused: bool,
+    grace: Duration,
+    since_finished: Option<Duration>,
+    max_preset: Option<Duration>,
+    was_clamped: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<DioxusTimer> for SerializedTimer {
+    fn from(timer: DioxusTimer) -> Self {
+        Self {
+            mode: timer.mode,
+            preset_duration: timer.preset_duration,
+            remaining_time: timer.remaining_time(),
+            elapsed: timer.elapsed(),
+            running_since: timer.running_since(),
+            since_finished: timer.since_finished(),
+            state: timer.state,
+            repeat: timer.repeat,
+            repeats_remaining: timer.repeats_remaining,
+            cycles_completed: timer.cycles_completed,
+            laps: timer.laps,
+            speed: timer.speed,
+            paused_total: timer.paused_total,
+            intervals: timer.intervals,
+            interval_index: timer.interval_index,
+            tick_interval: timer.tick_interval,
+            max_tick: timer.max_tick,
+            thresholds: timer.thresholds,
+            render_granularity: timer.render_granularity,
+            has_started: timer.has_started,
+            alarm_interval: timer.alarm_interval,
+            acknowledged: timer.acknowledged,
+            auto_pause: timer.auto_pause,
+            working_time: timer.working_time,
+            auto_paused: timer.auto_paused,
+            grace: timer.grace,
+            max_preset: timer.max_preset,
+            was_clamped: timer.was_clamped,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedTimer> for DioxusTimer {
+    fn from(data: SerializedTimer) -> Self {
+        let current_time = Instant::now();
+        let target_time = match data.mode {
+            TimerMode::Countdown => current_time
+                .checked_add(data.remaining_time)
+                .unwrap_or(current_time),
+            TimerMode::Stopwatch => current_time
+                .checked_sub(data.elapsed)
+                .unwrap_or(current_time),
+        };
+        let paused_time = (data.state == TimerState::Paused).then_some(current_time);
+        let mut timer = Self {
+            mode: data.mode,
+            preset_duration: data.preset_duration,
+            target_time,
+            state: data.state,
+            current_time,
+            paused_time,
+            repeat: data.repeat,
+            repeats_remaining: data.repeats_remaining,
+            cycles_completed: data.cycles_completed,
+            laps: data.laps,
+            speed: 1.0,
+            last_state: data.state,
+            transition: None,
+            tick_count: 0,
+            paused_total: data.paused_total,
+            intervals: data.intervals,
+            interval_index: data.interval_index,
+            started_at: data
+                .running_since
+                .and_then(|running_since| current_time.checked_sub(running_since)),
+            tick_interval: data.tick_interval,
+            max_tick: data.max_tick,
+            thresholds: data.thresholds,
+            render_granularity: data.render_granularity,
+            has_started: data.has_started,
+            alarm_interval: data.alarm_interval,
+            acknowledged: data.acknowledged,
+            alarm_due: false,
+            auto_pause: data.auto_pause,
+            working_time: data.working_time,
+            auto_paused: data.auto_paused,
+            grace: data.grace,
+            finished_at: data
+                .since_finished
+                .and_then(|since_finished| current_time.checked_sub(since_finished)),
+            max_preset: data.max_preset,
+            was_clamped: data.was_clamped,
+        };
+        timer.set_speed(data.speed);
+        timer
+    }
+}
+
+/// Manages a DioxusTimer instance within the Dioxus GUI framework.
+///
+/// `tick` is the interval of the background update loop. A display that only shows whole
+/// seconds can use `Duration::from_secs(1)` instead of the usual 16ms to save CPU and battery.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_timer;
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let mut timer = use_timer(Duration::from_millis(16));
+///use_effect(move || {
+///    spawn(async move {
+///        timer.write().set_and_start(Duration::from_secs(10));
+///    });
+///});
+///rsx!("{timer}")
+/// # }
+/// ```
+pub fn use_timer(tick: Duration) -> Signal<DioxusTimer> {
+    let timer = use_signal(DioxusTimer::new);
+    use_timer_driver(timer, tick, None);
+    timer
+}
+
+/// Manages a DioxusTimer instance without a background update loop.
+///
+/// Time only advances when the caller runs `update`, for example from its own render loop.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_timer_manual;
+/// # fn App() -> Element {
+///let mut timer = use_timer_manual();
+///rsx! {
+///    p { "{timer}" }
+///    button { onclick: move |_| timer.write().update(), "Next frame" }
+///}
+/// # }
+/// ```
+pub fn use_timer_manual() -> Signal<DioxusTimer> {
+    use_signal(DioxusTimer::new)
+}
+
+/// Manages a countdown DioxusTimer already set to `preset`, updated every 16ms.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_countdown;
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let mut timer = use_countdown(Duration::from_secs(10));
+///rsx! {
+///    p { "{timer}" }
+///    button { onclick: move |_| timer.write().start(), "Start" }
+///}
+/// # }
+/// ```
+pub fn use_countdown(preset: Duration) -> Signal<DioxusTimer> {
+    let timer = use_signal(|| DioxusTimer::with_preset(preset));
+    use_timer_driver(timer, DEFAULT_TICK, None);
+    timer
+}
+
+/// Manages a countdown like `use_countdown` that starts running on the first render.
+///
+/// The timer is only started once when the hook is created, not on every render.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_timer_autostart;
+/// # use std::time::Duration;
+/// # fn Splash() -> Element {
+///let timer = use_timer_autostart(Duration::from_secs(3));
+///rsx!("{timer}")
+/// # }
+/// ```
+pub fn use_timer_autostart(preset: Duration) -> Signal<DioxusTimer> {
+    let timer = use_signal(|| {
+        let mut timer = DioxusTimer::with_preset(preset);
+        timer.start();
+        timer
+    });
+    use_timer_driver(timer, DEFAULT_TICK, None);
+    timer
+}
+
+/// Manages a DioxusTimer instance like `use_timer` and calls `on_finish`
+/// once each time the timer transitions from `Working` to `Finished`.
+///
+/// The callback runs inside the update loop, so it may freely read or write other signals.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_timer_with_callback;
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let mut finished_count = use_signal(|| 0);
+///let timer = use_timer_with_callback(Duration::from_millis(16), move || {
+///    finished_count += 1;
+///});
+///rsx!("{timer} finished {finished_count} times")
+/// # }
+/// ```
+pub fn use_timer_with_callback(
+    tick: Duration,
+    mut on_finish: impl FnMut() + 'static,
+) -> Signal<DioxusTimer> {
+    let timer = use_signal(DioxusTimer::new);
+    let on_update = use_callback(move |prev_state| {
+        if prev_state == TimerState::Working && timer.peek().state() == TimerState::Finished {
+            on_finish();
+        }
+    });
+    use_timer_driver(timer, tick, Some(on_update));
+    timer
+}
+
+/// Manages a countdown like `use_countdown` together with its progress, calling `on_finish`
+/// once each time the countdown finishes.
+///
+/// Returns the timer and a memo of its progress from `0.0` to `1.0`, see `DioxusTimer::progress`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_countdown_with;
+/// # use std::time::Duration;
+/// # fn Upload() -> Element {
+///let mut done = use_signal(|| false);
+///let (mut timer, progress) = use_countdown_with(Duration::from_secs(5), move || done.set(true));
+///rsx! {
+///    progress { value: "{progress}" }
+///    button { onclick: move |_| timer.write().start(), "Upload" }
+///}
+/// # }
+/// ```
+pub fn use_countdown_with(
+    preset: Duration,
+    mut on_finish: impl FnMut() + 'static,
+) -> (Signal<DioxusTimer>, Memo<f32>) {
+    let timer = use_signal(|| DioxusTimer::with_preset(preset));
+    let on_update = use_callback(move |prev_state| {
+        if prev_state == TimerState::Working && timer.peek().state() == TimerState::Finished {
+            on_finish();
+        }
+    });
+    use_timer_driver(timer, DEFAULT_TICK, Some(on_update));
+    (timer, use_timer_progress(timer))
+}
+
+/// Manages a countdown like `use_countdown` and calls `on_tick` with the remaining time after
+/// each update while the countdown is `Working`.
+///
+/// The callback runs at the cadence of the update loop and stops once the countdown is paused,
+/// stopped or finished, so it suits animations and audio fades keyed off the exact remaining time.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_timer_on_tick;
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let mut volume = use_signal(|| 1.0);
+///let timer = use_timer_on_tick(Duration::from_secs(10), move |remaining| {
+///    volume.set(remaining.as_secs_f64() / 10.0);
+///});
+///rsx!("{timer}")
+/// # }
+/// ```
+pub fn use_timer_on_tick(
+    preset: Duration,
+    mut on_tick: impl FnMut(Duration) + 'static,
+) -> Signal<DioxusTimer> {
+    let timer = use_signal(|| DioxusTimer::with_preset(preset));
+    let on_update = use_callback(move |_| {
+        let remaining = {
+            let timer = timer.peek();
+            timer.is_running().then(|| timer.remaining_time())
+        };
+        if let Some(remaining) = remaining {
+            on_tick(remaining);
+        }
+    });
+    use_timer_driver(timer, DEFAULT_TICK, Some(on_update));
+    timer
+}
+
+/// Manages a DioxusTimer instance like `use_timer` and calls `on_change` with the old and
+/// new state each time an update observes a state change.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::use_timer_with_transitions;
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let timer = use_timer_with_transitions(Duration::from_millis(16), |old, new| {
+///    println!("{old} -> {new}");
+///});
+///rsx!("{timer}")
+/// # }
+/// ```
+pub fn use_timer_with_transitions(
+    tick: Duration,
+    mut on_change: impl FnMut(TimerState, TimerState) + 'static,
+) -> Signal<DioxusTimer> {
+    let timer = use_signal(DioxusTimer::new);
+    let on_update = use_callback(move |_| {
+        let transition = timer.peek().transition();
+        if let Some((old, new)) = transition {
+            on_change(old, new);
+        }
+    });
+    use_timer_driver(timer, tick, Some(on_update));
+    timer
+}
+
+/// Manages a DioxusTimer instance like `use_timer` and shows a browser notification titled
+/// `title` each time the timer transitions from `Working` to `Finished`.
+///
+/// Notification permission is requested on first use. Nothing is shown if permission is not
+/// granted, and on targets other than wasm32 no notification is ever shown.
+#[cfg(feature = "web-notifications")]
+pub fn use_timer_with_notification(tick: Duration, title: &str) -> Signal<DioxusTimer> {
+    use_hook(request_notification_permission);
+    let title = title.to_string();
+    use_timer_with_callback(tick, move || show_notification(&title))
+}
+
+#[cfg(all(feature = "web-notifications", target_arch = "wasm32"))]
+fn request_notification_permission() {
+    use web_sys::{Notification, NotificationPermission};
+    if Notification::permission() == NotificationPermission::Default {
+        let _ = Notification::request_permission();
+    }
+}
+
+#[cfg(all(feature = "web-notifications", target_arch = "wasm32"))]
+fn show_notification(title: &str) {
+    use web_sys::{Notification, NotificationPermission};
+    if Notification::permission() == NotificationPermission::Granted {
+        let _ = Notification::new(title);
+    }
+}
+
+#[cfg(all(feature = "web-notifications", not(target_arch = "wasm32")))]
+fn request_notification_permission() {}
+
+#[cfg(all(feature = "web-notifications", not(target_arch = "wasm32")))]
+fn show_notification(_title: &str) {}
+
+/// Pauses `timer` while the browser tab is hidden and resumes it once the tab is visible again.
+///
+/// Only timers paused by this hook are resumed. On targets other than wasm32 the hook does nothing.
+#[cfg(feature = "web-visibility")]
+pub fn use_timer_pause_on_blur(timer: Signal<DioxusTimer>) {
+    #[cfg(target_arch = "wasm32")]
+    use_pause_on_hidden(timer);
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = timer;
+}
+
+#[cfg(all(feature = "web-visibility", target_arch = "wasm32"))]
+fn use_pause_on_hidden(mut timer: Signal<DioxusTimer>) {
+    use std::rc::Rc;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let mut paused_by_blur = use_hook(|| CopyValue::new(false));
+    let listener = use_hook(move || {
+        let document = web_sys::window()?.document()?;
+        let target = document.clone();
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            if document.hidden() {
+                if timer.peek().is_running() {
+                    timer.write().pause();
+                    paused_by_blur.set(true);
+                }
+            } else if *paused_by_blur.peek() {
+                paused_by_blur.set(false);
+                timer.write().start();
+            }
+        });
+        target
+            .add_event_listener_with_callback("visibilitychange", callback.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Rc::new((target, callback)))
+    });
+    use_drop(move || {
+        if let Some(listener) = listener {
+            let (target, callback) = &*listener;
+            let _ = target.remove_event_listener_with_callback(
+                "visibilitychange",
+                callback.as_ref().unchecked_ref(),
+            );
+        }
+    });
+}
+
+/// Manages a DioxusTimer instance like `use_timer`, updated on every animation frame of the browser.
+///
+/// Updates follow the refresh rate of the display and stop while the tab is hidden.
+/// On targets other than wasm32 the hook falls back to the update loop of `use_timer` with a 16ms tick.
+#[cfg(feature = "web-animation-frame")]
+pub fn use_timer_raf() -> Signal<DioxusTimer> {
+    let timer = use_signal(DioxusTimer::new);
+    #[cfg(target_arch = "wasm32")]
+    use_animation_frame_driver(timer);
+    #[cfg(not(target_arch = "wasm32"))]
+    use_timer_driver(timer, DEFAULT_TICK, None);
+    timer
+}
+
+#[cfg(all(feature = "web-animation-frame", target_arch = "wasm32"))]
+fn use_animation_frame_driver(mut timer: Signal<DioxusTimer>) {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let frame = use_hook(move || {
+        let window = web_sys::window()?;
+        let request_id = Rc::new(Cell::new(None));
+        let callback = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+        let (frame_window, frame_request_id, frame_callback) =
+            (window.clone(), request_id.clone(), callback.clone());
+        *callback.borrow_mut() = Some(Closure::new(move || {
+            let is_update_due = timer.peek().is_update_due(Instant::now());
+            if is_update_due {
+                let updated = timer.peek().updated();
+                if let Some(updated) = updated {
+                    timer.set(updated);
+                }
+            }
+            if let Some(callback) = &*frame_callback.borrow() {
+                let id = frame_window.request_animation_frame(callback.as_ref().unchecked_ref());
+                frame_request_id.set(id.ok());
+            }
+        }));
+        let id =
+            window.request_animation_frame(callback.borrow().as_ref()?.as_ref().unchecked_ref());
+        request_id.set(id.ok());
+        Some(Rc::new((window, request_id, callback)))
+    });
+    use_drop(move || {
+        if let Some(frame) = frame {
+            let (window, request_id, callback) = &*frame;
+            if let Some(id) = request_id.take() {
+                let _ = window.cancel_animation_frame(id);
+            }
+            // Dropping the closure breaks the cycle through its own handle.
+            callback.borrow_mut().take();
         }
     });
+}
+
+/// Provides a DioxusTimer instance to all descendant components through the context.
+///
+/// The background update loop runs once in the providing component, every `tick`.
+/// A shared timer that only shows whole seconds can use a coarser tick, as with `use_timer`.
+/// Descendants obtain the shared timer with `use_timer_context`, which does not spawn a loop of its own.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::{use_timer_context, use_timer_context_provider};
+/// # use std::time::Duration;
+/// #[component]
+/// fn App() -> Element {
+///     use_timer_context_provider(Duration::from_millis(16));
+///     rsx! { Display {} }
+/// }
+///
+/// #[component]
+/// fn Display() -> Element {
+///     let timer = use_timer_context();
+///     rsx!("{timer}")
+/// }
+/// ```
+pub fn use_timer_context_provider(tick: Duration) -> Signal<DioxusTimer> {
+    let timer = use_context_provider(|| Signal::new(DioxusTimer::new()));
+    use_timer_driver(timer, tick, None);
     timer
 }
+
+/// Returns the DioxusTimer instance provided by `use_timer_context_provider` in an ancestor component.
+///
+/// # Panics
+///
+/// Panics if no ancestor provides a timer.
+pub fn use_timer_context() -> Signal<DioxusTimer> {
+    use_context()
+}
+
+/// Selects the remaining time of `timer`, so a component only re-renders when it changes.
+pub fn use_timer_remaining(timer: Signal<DioxusTimer>) -> Memo<Duration> {
+    use_memo(move || timer.read().remaining_time())
+}
+
+/// Selects the remaining whole seconds of `timer`, so a component only re-renders once per second.
+///
+/// The seconds are truncated like in the `Display` output.
+pub fn use_timer_seconds(timer: Signal<DioxusTimer>) -> Memo<u64> {
+    use_memo(move || timer.read().remaining_secs())
+}
+
+/// Selects the state of `timer`, so a component only re-renders when it changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::{use_timer, use_timer_state};
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let timer = use_timer(Duration::from_millis(16));
+///let state = use_timer_state(timer);
+///rsx!("{state}")
+/// # }
+/// ```
+pub fn use_timer_state(timer: Signal<DioxusTimer>) -> Memo<TimerState> {
+    use_memo(move || timer.read().state())
+}
+
+/// Selects the progress of `timer`, so a component only re-renders when it changes.
+pub fn use_timer_progress(timer: Signal<DioxusTimer>) -> Memo<f32> {
+    use_memo(move || timer.read().progress())
+}
+
+/// Returns a stream of the remaining time of `timer`, sampled every `tick`.
+///
+/// The first value is yielded right away. The stream ends after yielding the value
+/// observed once the timer is `Finished`. `timer` must be driven by a hook such as `use_timer`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::{remaining_stream, use_countdown};
+/// # use futures_util::StreamExt;
+/// # use std::time::Duration;
+/// # fn App() -> Element {
+///let timer = use_countdown(Duration::from_secs(10));
+///use_future(move || async move {
+///    let mut remaining = std::pin::pin!(remaining_stream(timer, Duration::from_secs(1)));
+///    while let Some(time) = remaining.next().await {
+///        println!("{time:?} left");
+///    }
+///});
+///rsx!("{timer}")
+/// # }
+/// ```
+pub fn remaining_stream(
+    timer: Signal<DioxusTimer>,
+    tick: Duration,
+) -> impl Stream<Item = Duration> {
+    futures_util::stream::unfold(Some(Duration::ZERO), move |delay| async move {
+        sleep(delay?).await;
+        let timer = timer.peek();
+        let next_delay = (!timer.is_finished()).then_some(tick);
+        Some((timer.remaining_time(), next_delay))
+    })
+}
+
+/// Interval of the update loop used by hooks that do not take a tick.
+const DEFAULT_TICK: Duration = Duration::from_millis(16);
+
+/// Interval of the update loop while the timer is `Inactive`, `Stopped` or `Finished`.
+const IDLE_TICK: Duration = Duration::from_millis(250);
+
+/// Spawns the background loop that updates `timer` every `tick`.
+///
+/// Ticks follow a fixed schedule, so the time spent updating does not delay the next tick.
+/// Ticks are skipped while the timer's own tick interval has not passed, see `DioxusTimer::set_tick_interval`.
+/// While the timer is `Inactive`, `Stopped` or `Finished` the loop backs off to `IDLE_TICK`.
+/// A `Working` countdown also wakes the loop at its deadline, so the finish transition and
+/// `on_update` are not delayed until the next tick.
+/// Updates hidden by the timer's render granularity are not written back, see
+/// `DioxusTimer::set_render_granularity`.
+/// `on_update` is called after each written update with the state the timer had before it.
+fn use_timer_driver(
+    mut timer: Signal<DioxusTimer>,
+    tick: Duration,
+    on_update: Option<Callback<TimerState>>,
+) {
+    use_future(move || async move {
+        let mut next_tick = Instant::now();
+        loop {
+            let now = Instant::now();
+            let is_update_due = {
+                let timer = timer.peek();
+                timer.is_update_due(now) || timer.wake_at().is_some_and(|wake_at| wake_at <= now)
+            };
+            if is_update_due {
+                let prev_state = timer.peek().state();
+                let updated = timer.peek().updated();
+                if let Some(updated) = updated {
+                    timer.set(updated);
+                    if let Some(on_update) = on_update {
+                        on_update.call(prev_state);
+                    }
+                }
+            }
+            let (is_idle, wake_at) = {
+                let timer = timer.peek();
+                (is_idle(timer.state()), timer.wake_at())
+            };
+            sleep_until_next_tick(&mut next_tick, tick, is_idle, wake_at).await;
+        }
+    });
+}
+
+/// Returns `true` for states in which the update loop backs off to `IDLE_TICK`.
+fn is_idle(state: TimerState) -> bool {
+    matches!(
+        state,
+        TimerState::Inactive | TimerState::Stopped | TimerState::Finished
+    )
+}
+
+/// Advances `next_tick` by one interval once it has passed and sleeps until then, or until
+/// `wake_at` if that comes first.
+async fn sleep_until_next_tick(
+    next_tick: &mut Instant,
+    tick: Duration,
+    is_idle: bool,
+    wake_at: Option<Instant>,
+) {
+    let now = Instant::now();
+    let wake_at = schedule_next_tick(next_tick, now, tick, is_idle, wake_at);
+    sleep(wake_at - now).await;
+}
+
+/// Returns the instant the update loop should wake at, advancing `next_tick` once it has passed.
+///
+/// If the loop has fallen behind, the next tick is scheduled right away instead of catching up.
+fn schedule_next_tick(
+    next_tick: &mut Instant,
+    now: Instant,
+    tick: Duration,
+    is_idle: bool,
+    wake_at: Option<Instant>,
+) -> Instant {
+    let interval = if is_idle { tick.max(IDLE_TICK) } else { tick };
+    if *next_tick <= now {
+        *next_tick = next_tick.checked_add(interval).unwrap_or(now).max(now);
+    }
+    wake_at
+        .filter(|&wake_at| wake_at > now)
+        .map_or(*next_tick, |wake_at| wake_at.min(*next_tick))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs the scheduling of the update loop against `timer` without sleeping and returns the
+    /// instant its update observed `Finished`.
+    fn finish_detected_at(mut timer: DioxusTimer, tick: Duration, use_wake_at: bool) -> Instant {
+        let mut next_tick = timer.last_update();
+        let mut now = next_tick;
+        for _ in 0..1000 {
+            let wake_at = use_wake_at.then(|| timer.wake_at()).flatten();
+            now = schedule_next_tick(&mut next_tick, now, tick, is_idle(timer.state()), wake_at);
+            if timer.is_update_due(now) {
+                timer.update_at(now);
+            }
+            if timer.is_finished() {
+                return now;
+            }
+        }
+        panic!("timer did not finish");
+    }
+
+    #[test]
+    fn fixed_schedule_detects_finish_within_one_tick() {
+        let tick = Duration::from_millis(16);
+        let mut timer = DioxusTimer::with_preset(Duration::from_millis(100));
+        timer.start();
+        let deadline = timer.finish_at().unwrap();
+        let detected_at = finish_detected_at(timer, tick, false);
+        assert!(detected_at >= deadline);
+        assert!(detected_at - deadline <= tick);
+    }
+
+    #[test]
+    fn wake_at_detects_finish_at_the_deadline() {
+        let mut timer = DioxusTimer::with_preset(Duration::from_millis(100));
+        timer.start();
+        let deadline = timer.finish_at().unwrap();
+        let detected_at = finish_detected_at(timer, Duration::from_secs(1), true);
+        assert_eq!(detected_at, deadline);
+    }
+
+    #[test]
+    fn granularity_writes_back_growing_since_finished() {
+        let mut timer = DioxusTimer::from_secs(5);
+        timer.set_render_granularity(Duration::from_secs(1));
+        timer.set_target_instant(Instant::now());
+        timer.update();
+        assert!(timer.is_finished());
+        // Move the finish into the past, as if the driver loop had been skipping updates.
+        let skipped = Duration::from_millis(1500);
+        timer.current_time -= skipped;
+        timer.finished_at = timer.finished_at.map(|finished_at| finished_at - skipped);
+        let updated = timer.updated().expect("since_finished crossed a second");
+        assert!(updated.since_finished().unwrap() >= skipped);
+    }
+
+    #[test]
+    fn pause_follows_the_virtual_clock() {
+        let mut timer = DioxusTimer::from_secs(60);
+        timer.start();
+        timer.advance(Duration::from_secs(10));
+        timer.pause();
+        timer.advance(Duration::from_secs(5));
+        timer.start();
+        timer.advance(Duration::from_secs(5));
+        assert_eq!(timer.remaining_time(), Duration::from_secs(45));
+        assert_eq!(timer.total_paused_time(), Duration::from_secs(5));
+
+        let mut stopwatch = DioxusTimer::new_stopwatch();
+        stopwatch.start();
+        stopwatch.advance(Duration::from_secs(10));
+        stopwatch.pause();
+        stopwatch.advance(Duration::from_secs(5));
+        stopwatch.start();
+        stopwatch.advance(Duration::from_secs(5));
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(15));
+    }
+
+    #[test]
+    fn stop_keeps_laps_and_paused_time_until_start() {
+        let mut stopwatch = DioxusTimer::new_stopwatch();
+        stopwatch.start();
+        stopwatch.advance(Duration::from_secs(1));
+        stopwatch.lap();
+        stopwatch.pause();
+        stopwatch.advance(Duration::from_secs(2));
+        stopwatch.lap();
+        stopwatch.stop();
+        stopwatch.advance(Duration::from_secs(1));
+        assert_eq!(stopwatch.state(), TimerState::Stopped);
+        assert_eq!(stopwatch.laps().len(), 2);
+        assert_eq!(stopwatch.total_paused_time(), Duration::from_secs(2));
+        stopwatch.start();
+        assert!(stopwatch.laps().is_empty());
+        assert_eq!(stopwatch.total_paused_time(), Duration::ZERO);
+    }
+
+    #[test]
+    fn stop_and_reset_clear_the_auto_pause() {
+        let mut timer = DioxusTimer::from_secs(60);
+        timer.set_auto_pause(Some(Duration::from_secs(20)));
+        timer.start();
+        timer.advance(Duration::from_secs(20));
+        assert!(timer.auto_paused());
+        timer.stop();
+        assert_eq!(timer.state(), TimerState::Stopped);
+        assert!(!timer.auto_paused());
+
+        timer.start();
+        timer.advance(Duration::from_secs(15));
+        timer.reset();
+        assert_eq!(timer.working_time, Duration::ZERO);
+        timer.advance(Duration::from_secs(15));
+        assert_eq!(timer.state(), TimerState::Working);
+    }
+
+    #[test]
+    fn fallible_setters_reject_a_stopwatch() {
+        let mut stopwatch = DioxusTimer::new_stopwatch();
+        let when = Instant::now() + Duration::from_secs(60);
+        assert_eq!(
+            stopwatch.try_set_target_instant(when),
+            Err(TimerError::PresetLocked)
+        );
+        let intervals = vec![Duration::from_secs(30)];
+        assert_eq!(
+            stopwatch.try_set_intervals(intervals),
+            Err(TimerError::PresetLocked)
+        );
+        assert_eq!(stopwatch.state(), TimerState::Inactive);
+    }
+
+    #[test]
+    fn stopwatch_round_trips_through_snapshot_and_query() {
+        let mut stopwatch = DioxusTimer::new_stopwatch();
+        stopwatch.start();
+        stopwatch.advance(Duration::from_secs(30));
+        stopwatch.pause();
+        let restored = DioxusTimer::from_snapshot(stopwatch.snapshot());
+        assert_eq!(restored.mode(), TimerMode::Stopwatch);
+        assert_eq!(restored.state(), TimerState::Paused);
+        assert_eq!(restored.elapsed(), Duration::from_secs(30));
+        let restored = DioxusTimer::from_query(&stopwatch.to_query()).unwrap();
+        assert_eq!(restored.mode(), TimerMode::Stopwatch);
+        assert_eq!(restored.elapsed(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn paused_rewind_and_preset_keep_the_full_preset() {
+        let mut timer = DioxusTimer::from_secs(10);
+        timer.start();
+        timer.advance(Duration::from_secs(4));
+        timer.pause();
+        timer.advance(Duration::from_secs(1));
+        timer.rewind();
+        timer.advance(Duration::from_secs(1));
+        assert_eq!(timer.remaining_time(), Duration::from_secs(10));
+        timer.set_preset_time(Duration::from_secs(20));
+        timer.advance(Duration::from_secs(1));
+        assert_eq!(timer.remaining_time(), Duration::from_secs(20));
+    }
+
+    #[test]
+    fn snooze_finishing_within_one_update_is_a_new_finish() {
+        let mut timer = DioxusTimer::from_secs(5);
+        timer.start();
+        timer.advance(Duration::from_secs(5));
+        timer.acknowledge();
+        timer.advance(Duration::from_secs(2));
+        timer.snooze(Duration::from_millis(10));
+        assert!(!timer.is_acknowledged());
+        assert_eq!(timer.since_finished(), None);
+        timer.advance(Duration::from_millis(16));
+        assert_eq!(timer.state(), TimerState::Finished);
+        assert_eq!(
+            timer.transition(),
+            Some((TimerState::Working, TimerState::Finished))
+        );
+        assert_eq!(timer.since_finished(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn paused_without_pause_instant_does_not_panic() {
+        let mut timer = DioxusTimer::with_preset(Duration::from_secs(10));
+        timer.start();
+        timer.pause();
+        timer.paused_time = None;
+        timer.update();
+        assert_eq!(timer.state(), TimerState::Paused);
+        assert!(timer.paused_time.is_some());
+    }
+
+    #[test]
+    fn fixed_schedule_does_not_drift() {
+        let tick = Duration::from_millis(16);
+        let start = Instant::now();
+        let mut next_tick = start;
+        let mut now = start;
+        for n in 1..=100 {
+            // Each wake-up is late, which must not delay the ticks after it.
+            let late = Duration::from_millis(3);
+            now = schedule_next_tick(&mut next_tick, now, tick, false, None) + late;
+            assert_eq!(next_tick, start + tick * n);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{is_idle, sleep_until_next_tick, DioxusTimer, TimerState};
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+#[cfg(target_arch = "wasm32")]
+use instant::{Duration, Instant};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+/// Named DioxusTimer instances shared through the context and driven by a single update loop.
+///
+/// Created by `use_timer_map_provider` and obtained in descendants with `use_timer_map`.
+/// Methods taking a name do nothing or return `None` for unknown names.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_timer::{use_timer_map, use_timer_map_provider, DioxusTimer};
+/// # use std::time::Duration;
+/// #[component]
+/// fn App() -> Element {
+///     let mut timers = use_timer_map_provider(Duration::from_millis(16));
+///     use_hook(move || {
+///         timers.insert("tea", DioxusTimer::with_preset(Duration::from_secs(180)));
+///     });
+///     rsx! { Tea {} }
+/// }
+///
+/// #[component]
+/// fn Tea() -> Element {
+///     let mut timers = use_timer_map();
+///     let remaining = timers.remaining("tea").unwrap_or_default();
+///     rsx! {
+///         p { "{remaining:?}" }
+///         button { onclick: move |_| timers.start("tea"), "Start" }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimerMap {
+    timers: Signal<HashMap<String, DioxusTimer>>,
+}
+
+impl TimerMap {
+    /// Adds a timer under `name`, replacing any timer with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, timer: DioxusTimer) {
+        self.timers.write().insert(name.into(), timer);
+    }
+
+    /// Removes and returns the timer named `name`.
+    pub fn remove(&mut self, name: &str) -> Option<DioxusTimer> {
+        self.timers.write().remove(name)
+    }
+
+    /// Starts the timer named `name`, see `DioxusTimer::start`.
+    pub fn start(&mut self, name: &str) {
+        if let Some(timer) = self.timers.write().get_mut(name) {
+            timer.start();
+        }
+    }
+
+    /// Pauses the timer named `name`, see `DioxusTimer::pause`.
+    pub fn pause(&mut self, name: &str) {
+        if let Some(timer) = self.timers.write().get_mut(name) {
+            timer.pause();
+        }
+    }
+
+    /// Pauses every `Working` timer in the map.
+    pub fn pause_all(&mut self) {
+        for timer in self.timers.write().values_mut() {
+            timer.pause();
+        }
+    }
+
+    /// Resumes every `Paused` timer in the map, leaving `Inactive` ones untouched.
+    pub fn resume_all(&mut self) {
+        for timer in self.timers.write().values_mut() {
+            if timer.is_paused() {
+                timer.start();
+            }
+        }
+    }
+
+    /// Resets every timer in the map, see `DioxusTimer::reset`.
+    pub fn reset_all(&mut self) {
+        for timer in self.timers.write().values_mut() {
+            timer.reset();
+        }
+    }
+
+    /// Returns the remaining time of the timer named `name`.
+    pub fn remaining(&self, name: &str) -> Option<Duration> {
+        self.timers
+            .read()
+            .get(name)
+            .map(DioxusTimer::remaining_time)
+    }
+
+    /// Returns the state of the timer named `name`.
+    pub fn state(&self, name: &str) -> Option<TimerState> {
+        self.timers.read().get(name).map(DioxusTimer::state)
+    }
+
+    /// Returns the signal holding all timers, for access beyond the helper methods.
+    pub fn timers(&self) -> Signal<HashMap<String, DioxusTimer>> {
+        self.timers
+    }
+}
+
+/// Provides an empty `TimerMap` to all descendant components through the context.
+///
+/// A single background loop updates every timer in the map once per `tick`, and at the
+/// deadline of each `Working` countdown. The map is only written when an update is visible at the
+/// render granularity of its timer, see `DioxusTimer::set_render_granularity`.
+pub fn use_timer_map_provider(tick: Duration) -> TimerMap {
+    let timer_map = use_context_provider(|| TimerMap {
+        timers: Signal::new(HashMap::new()),
+    });
+    let mut timers = timer_map.timers;
+    use_future(move || async move {
+        let mut next_tick = Instant::now();
+        loop {
+            let now = Instant::now();
+            let is_due = |timer: &DioxusTimer| {
+                timer.is_update_due(now) || timer.wake_at().is_some_and(|wake_at| wake_at <= now)
+            };
+            let updates: Vec<_> = timers
+                .peek()
+                .iter()
+                .filter(|(_, timer)| is_due(timer))
+                .filter_map(|(name, timer)| Some((name.clone(), timer.updated()?)))
+                .collect();
+            if !updates.is_empty() {
+                timers.write().extend(updates);
+            }
+            let (is_idle, wake_at) = {
+                let timers = timers.peek();
+                (
+                    timers.values().all(|timer| is_idle(timer.state())),
+                    timers.values().filter_map(DioxusTimer::wake_at).min(),
+                )
+            };
+            sleep_until_next_tick(&mut next_tick, tick, is_idle, wake_at).await;
+        }
+    });
+    timer_map
+}
+
+/// Returns the `TimerMap` provided by `use_timer_map_provider` in an ancestor component.
+///
+/// # Panics
+///
+/// Panics if no ancestor provides a timer map.
+pub fn use_timer_map() -> TimerMap {
+    use_context()
+}